@@ -0,0 +1,77 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte array as base32 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use standard, padded base32
+//! from [RFC 4648 §6]. The submodules provide the same functions for other
+//! common variants:
+//!
+//! * [`no_pad`]: the standard alphabet, without padding.
+//! * [`hex`]: the extended hex alphabet from [RFC 4648 §7], with padding.
+//! * [`hex_no_pad`]: the extended hex alphabet, without padding.
+//! * [`crockford`]: [Crockford's base32], without padding.
+//!
+//! All variants encode in upper case and decode either case. See
+//! [`encoding`](crate::encoding) for details of each.
+//!
+//! [RFC 4648 §6]: https://datatracker.ietf.org/doc/html/rfc4648#section-6
+//! [RFC 4648 §7]: https://datatracker.ietf.org/doc/html/rfc4648#section-7
+//! [Crockford's base32]: https://www.crockford.com/base32.html
+
+/// Defines `serialize` and `deserialize` functions for byte arrays in
+/// `$encoding`.
+macro_rules! base32_array_functions {
+    ($encoding:ty, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte arrays to ", $description, " if")]
+        #[doc = "human-readable, or as bytes if not."]
+        pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde_core::Serializer,
+        {
+            crate::human_bytes_type::serialize::<$encoding, S>(bytes, serializer)
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s (if human-readable) or byte arrays")]
+        #[doc = "(if not) to `[u8; N]`."]
+        #[doc = ""]
+        #[doc = "In self-describing binary formats such as CBOR, a sequence of exactly `N`"]
+        #[doc = "integers is also accepted, as produced by serde's own implementation for"]
+        #[doc = "`[u8; N]`. Formats that aren't self-describing, such as postcard and"]
+        #[doc = "bincode, only accept the length-prefixed bytes written by [`serialize`]."]
+        pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+        where
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<$encoding, [u8; N]>::deserialize(deserializer)
+        }
+    };
+}
+
+base32_array_functions!(crate::encoding::Base32, "a base32 string");
+
+/// Serialization using the standard base32 alphabet, without padding.
+pub mod no_pad {
+    base32_array_functions!(crate::encoding::Base32NoPad, "an unpadded base32 string");
+}
+
+/// Serialization using the extended hex base32 alphabet, with padding.
+pub mod hex {
+    base32_array_functions!(crate::encoding::Base32Hex, "a base32hex string");
+}
+
+/// Serialization using the extended hex base32 alphabet, without padding.
+pub mod hex_no_pad {
+    base32_array_functions!(
+        crate::encoding::Base32HexNoPad,
+        "an unpadded base32hex string"
+    );
+}
+
+/// Serialization using Crockford's base32 alphabet, without padding.
+pub mod crockford {
+    base32_array_functions!(
+        crate::encoding::Base32Crockford,
+        "a Crockford base32 string"
+    );
+}
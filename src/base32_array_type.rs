@@ -0,0 +1,39 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Base32Array`] type alias, and its siblings for other base32
+//! alphabets.
+
+use crate::{
+    HumanBytes,
+    encoding::{Base32, Base32Crockford, Base32Hex, Base32HexNoPad, Base32NoPad},
+};
+
+/// A byte array that serializes as base32 in human-readable formats.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Base32Array::<N>")]` and
+///    `#[schemars(with = "Base32Array<N>")]` on a `[u8; N]` field.
+///
+/// This uses the standard, padded base32 alphabet. For other variants, see
+/// [`Base32NoPadArray`], [`Base32HexArray`], [`Base32HexNoPadArray`] and
+/// [`Base32CrockfordArray`].
+pub type Base32Array<const N: usize> = HumanBytes<Base32, [u8; N]>;
+
+/// A byte array that serializes as unpadded base32 in human-readable
+/// formats.
+pub type Base32NoPadArray<const N: usize> = HumanBytes<Base32NoPad, [u8; N]>;
+
+/// A byte array that serializes as base32 with the extended hex alphabet in
+/// human-readable formats.
+pub type Base32HexArray<const N: usize> = HumanBytes<Base32Hex, [u8; N]>;
+
+/// A byte array that serializes as unpadded base32 with the extended hex
+/// alphabet in human-readable formats.
+pub type Base32HexNoPadArray<const N: usize> = HumanBytes<Base32HexNoPad, [u8; N]>;
+
+/// A byte array that serializes as Crockford's base32 in human-readable
+/// formats.
+pub type Base32CrockfordArray<const N: usize> = HumanBytes<Base32Crockford, [u8; N]>;
@@ -0,0 +1,72 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte vector as base32 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use standard, padded base32
+//! from [RFC 4648 §6]. The submodules provide the same functions for other
+//! common variants:
+//!
+//! * [`no_pad`]: the standard alphabet, without padding.
+//! * [`hex`]: the extended hex alphabet from [RFC 4648 §7], with padding.
+//! * [`hex_no_pad`]: the extended hex alphabet, without padding.
+//! * [`crockford`]: [Crockford's base32], without padding.
+//!
+//! All variants encode in upper case and decode either case. See
+//! [`encoding`](crate::encoding) for details of each.
+//!
+//! [RFC 4648 §6]: https://datatracker.ietf.org/doc/html/rfc4648#section-6
+//! [RFC 4648 §7]: https://datatracker.ietf.org/doc/html/rfc4648#section-7
+//! [Crockford's base32]: https://www.crockford.com/base32.html
+
+/// Defines `serialize` and `deserialize` functions for byte vectors in
+/// `$encoding`.
+macro_rules! base32_vec_functions {
+    ($encoding:ty, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte vectors to ", $description, " if")]
+        #[doc = "human-readable, or as bytes if not."]
+        pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde_core::Serializer,
+        {
+            crate::human_bytes_type::serialize::<$encoding, S>(bytes, serializer)
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s (if human-readable) or byte arrays")]
+        #[doc = "(if not) to `Vec<u8>`."]
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<alloc::vec::Vec<u8>, D::Error>
+        where
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<$encoding, alloc::vec::Vec<u8>>::deserialize(deserializer)
+        }
+    };
+}
+
+base32_vec_functions!(crate::encoding::Base32, "a base32 string");
+
+/// Serialization using the standard base32 alphabet, without padding.
+pub mod no_pad {
+    base32_vec_functions!(crate::encoding::Base32NoPad, "an unpadded base32 string");
+}
+
+/// Serialization using the extended hex base32 alphabet, with padding.
+pub mod hex {
+    base32_vec_functions!(crate::encoding::Base32Hex, "a base32hex string");
+}
+
+/// Serialization using the extended hex base32 alphabet, without padding.
+pub mod hex_no_pad {
+    base32_vec_functions!(
+        crate::encoding::Base32HexNoPad,
+        "an unpadded base32hex string"
+    );
+}
+
+/// Serialization using Crockford's base32 alphabet, without padding.
+pub mod crockford {
+    base32_vec_functions!(
+        crate::encoding::Base32Crockford,
+        "a Crockford base32 string"
+    );
+}
@@ -0,0 +1,40 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Base32Vec`] type alias, and its siblings for other base32
+//! alphabets.
+
+use crate::{
+    HumanBytes,
+    encoding::{Base32, Base32Crockford, Base32Hex, Base32HexNoPad, Base32NoPad},
+};
+use alloc::vec::Vec;
+
+/// A byte vector that serializes as base32 in human-readable formats.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Base32Vec")]` and `#[schemars(with = "Base32Vec")]`
+///    on a `Vec<u8>` field.
+///
+/// This uses the standard, padded base32 alphabet. For other variants, see
+/// [`Base32NoPadVec`], [`Base32HexVec`], [`Base32HexNoPadVec`] and
+/// [`Base32CrockfordVec`].
+pub type Base32Vec = HumanBytes<Base32, Vec<u8>>;
+
+/// A byte vector that serializes as unpadded base32 in human-readable
+/// formats.
+pub type Base32NoPadVec = HumanBytes<Base32NoPad, Vec<u8>>;
+
+/// A byte vector that serializes as base32 with the extended hex alphabet in
+/// human-readable formats.
+pub type Base32HexVec = HumanBytes<Base32Hex, Vec<u8>>;
+
+/// A byte vector that serializes as unpadded base32 with the extended hex
+/// alphabet in human-readable formats.
+pub type Base32HexNoPadVec = HumanBytes<Base32HexNoPad, Vec<u8>>;
+
+/// A byte vector that serializes as Crockford's base32 in human-readable
+/// formats.
+pub type Base32CrockfordVec = HumanBytes<Base32Crockford, Vec<u8>>;
@@ -0,0 +1,24 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Base58Array`] and [`Base58CheckArray`] type aliases.
+
+use crate::{
+    HumanBytes,
+    encoding::{Base58, Base58Check},
+};
+
+/// A byte array that serializes as base58 in human-readable formats.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Base58Array::<N>")]` and
+///    `#[schemars(with = "Base58Array<N>")]` on a `[u8; N]` field.
+pub type Base58Array<const N: usize> = HumanBytes<Base58, [u8; N]>;
+
+/// A byte array that serializes as Base58Check in human-readable formats.
+///
+/// The checksum is not part of the array: it's appended when serializing, and
+/// verified and removed when deserializing.
+pub type Base58CheckArray<const N: usize> = HumanBytes<Base58Check, [u8; N]>;
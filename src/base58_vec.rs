@@ -0,0 +1,40 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte vector as base58 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use base58 with the Bitcoin
+//! alphabet. The [`check`] submodule provides the same functions for
+//! Base58Check, which appends a 4-byte checksum. See
+//! [`encoding`](crate::encoding) for details of each.
+
+/// Defines `serialize` and `deserialize` functions for byte vectors in
+/// `$encoding`.
+macro_rules! base58_vec_functions {
+    ($encoding:ty, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte vectors to ", $description, " if")]
+        #[doc = "human-readable, or as bytes if not."]
+        pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde_core::Serializer,
+        {
+            crate::human_bytes_type::serialize::<$encoding, S>(bytes, serializer)
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s (if human-readable) or byte arrays")]
+        #[doc = "(if not) to `Vec<u8>`."]
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<alloc::vec::Vec<u8>, D::Error>
+        where
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<$encoding, alloc::vec::Vec<u8>>::deserialize(deserializer)
+        }
+    };
+}
+
+base58_vec_functions!(crate::encoding::Base58, "a base58 string");
+
+/// Serialization using Base58Check, which appends a checksum.
+pub mod check {
+    base58_vec_functions!(crate::encoding::Base58Check, "a Base58Check string");
+}
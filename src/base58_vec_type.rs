@@ -0,0 +1,25 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Base58Vec`] and [`Base58CheckVec`] type aliases.
+
+use crate::{
+    HumanBytes,
+    encoding::{Base58, Base58Check},
+};
+use alloc::vec::Vec;
+
+/// A byte vector that serializes as base58 in human-readable formats.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Base58Vec")]` and `#[schemars(with = "Base58Vec")]`
+///    on a `Vec<u8>` field.
+pub type Base58Vec = HumanBytes<Base58, Vec<u8>>;
+
+/// A byte vector that serializes as Base58Check in human-readable formats.
+///
+/// The checksum is not part of the vector: it's appended when serializing,
+/// and verified and removed when deserializing.
+pub type Base58CheckVec = HumanBytes<Base58Check, Vec<u8>>;
@@ -0,0 +1,31 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte array as base64 if human-readable, or as bytes if not.
+
+use serde_core::{Deserializer, Serializer};
+
+/// Implements serialization for byte arrays to a base64 string if
+/// human-readable, or as bytes if not.
+///
+/// This should work transparently with any `[u8; N]`.
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::human_bytes_type::serialize::<crate::encoding::Base64, S>(bytes, serializer)
+}
+
+/// Deserializes base64 strings (if human-readable) or byte arrays (if not) to
+/// `[u8; N]`.
+///
+/// In self-describing binary formats such as CBOR, a sequence of exactly `N`
+/// integers is also accepted, as produced by serde's own implementation for
+/// `[u8; N]`. Formats that aren't self-describing, such as postcard and
+/// bincode, only accept the length-prefixed bytes written by [`serialize`].
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::Base64Array::<N>::deserialize(deserializer)
+}
@@ -0,0 +1,15 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Base64Array`] type alias.
+
+use crate::{HumanBytes, encoding::Base64};
+
+/// A byte array that serializes as base64 in human-readable formats.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Base64Array::<N>")]` and
+///    `#[schemars(with = "Base64Array<N>")]` on a `[u8; N]` field.
+pub type Base64Array<const N: usize> = HumanBytes<Base64, [u8; N]>;
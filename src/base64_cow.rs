@@ -0,0 +1,83 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte slice as base64 if human-readable, or as bytes if not,
+//! borrowing from the input when deserializing where possible.
+//!
+//! In non-human-readable formats that support it (such as postcard, or
+//! MessagePack read from a slice), deserialization borrows bytes directly from
+//! the input buffer, producing a [`Cow::Borrowed`]. Base64 text, and formats
+//! that can't lend out their input, produce a [`Cow::Owned`].
+
+use crate::{base64_vec, byte_visitor::ByteVecVisitor};
+use alloc::{borrow::Cow, vec::Vec};
+use core::fmt;
+use serde_core::{
+    Deserializer, Serializer,
+    de::{SeqAccess, Visitor},
+};
+
+/// Implements serialization for byte slices to a base64 string if
+/// human-readable, or as bytes if not.
+///
+/// This is the same as [`base64_vec::serialize`].
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    base64_vec::serialize(bytes, serializer)
+}
+
+/// Deserializes base64 strings (if human-readable) or byte arrays (if not) to
+/// `Cow<'a, [u8]>`, borrowing from the input where possible.
+///
+/// When used with `#[serde(with = "...")]`, the field must also be annotated
+/// with `#[serde(borrow)]` for the derived implementation to borrow.
+pub fn deserialize<'de: 'a, 'a, D>(deserializer: D) -> Result<Cow<'a, [u8]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        base64_vec::deserialize(deserializer).map(Cow::Owned)
+    } else {
+        deserializer.deserialize_bytes(CowBytesVisitor)
+    }
+}
+
+struct CowBytesVisitor;
+
+impl<'de> Visitor<'de> for CowBytesVisitor {
+    type Value = Cow<'de, [u8]>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte array")
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        Ok(Cow::Borrowed(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        ByteVecVisitor.visit_bytes(v).map(Cow::Owned)
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        ByteVecVisitor.visit_byte_buf(v).map(Cow::Owned)
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        ByteVecVisitor.visit_seq(seq).map(Cow::Owned)
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Base64Cow`] newtype wrapper.
+
+use crate::base64_cow;
+use alloc::{borrow::Cow, vec::Vec};
+use base64::Engine;
+use core::fmt;
+use serde_core::{Deserializer, Serializer};
+
+/// A possibly-borrowed byte slice that serializes as base64 in human-readable
+/// formats.
+///
+/// In non-human-readable formats that support it, deserializing this type
+/// borrows from the input rather than allocating. See [`base64_cow`] for
+/// details. Since a `Base64Cow<'a>` can only be deserialized from input that
+/// outlives `'a`, it can't be used with APIs that require
+/// `DeserializeOwned`, such as reader-based deserializers; use
+/// [`Base64Vec`](crate::Base64Vec) there instead.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in. Annotate the field
+///    with `#[serde(borrow)]` to borrow from the input.
+/// 2. With `#[serde(borrow, with = "Base64Cow")]` and
+///    `#[schemars(with = "Base64Cow")]` on a `Cow<'a, [u8]>` field.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct Base64Cow<'a>(pub Cow<'a, [u8]>);
+
+impl<'a> Base64Cow<'a> {
+    /// Creates a new `Base64Cow` from a possibly-borrowed byte slice.
+    #[inline]
+    pub fn new(bytes: Cow<'a, [u8]>) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the inner `Cow`.
+    #[inline]
+    pub fn into_inner(self) -> Cow<'a, [u8]> {
+        self.0
+    }
+
+    /// Converts into a `Base64Cow` that owns its data, cloning it if it was
+    /// borrowed.
+    #[inline]
+    pub fn into_owned(self) -> Base64Cow<'static> {
+        Base64Cow(Cow::Owned(self.0.into_owned()))
+    }
+
+    /// Serializes a byte slice as base64 (for `#[serde(with = "Base64Cow")]`).
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        base64_cow::serialize(bytes, serializer)
+    }
+
+    /// Deserializes a possibly-borrowed byte slice from base64 (for
+    /// `#[serde(borrow, with = "Base64Cow")]`).
+    pub fn deserialize<'de: 'a, D>(deserializer: D) -> Result<Cow<'a, [u8]>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        base64_cow::deserialize(deserializer)
+    }
+}
+
+impl fmt::Debug for Base64Cow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Base64Cow({})",
+            base64::engine::general_purpose::STANDARD.encode(&self.0)
+        )
+    }
+}
+
+impl fmt::Display for Base64Cow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        base64::engine::general_purpose::STANDARD
+            .encode(&self.0)
+            .fmt(f)
+    }
+}
+
+impl<'a> core::ops::Deref for Base64Cow<'a> {
+    type Target = Cow<'a, [u8]>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl core::ops::DerefMut for Base64Cow<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Base64Cow<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<'a> From<Cow<'a, [u8]>> for Base64Cow<'a> {
+    #[inline]
+    fn from(bytes: Cow<'a, [u8]>) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<'a> From<&'a [u8]> for Base64Cow<'a> {
+    #[inline]
+    fn from(bytes: &'a [u8]) -> Self {
+        Self(Cow::Borrowed(bytes))
+    }
+}
+
+impl From<Vec<u8>> for Base64Cow<'_> {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(Cow::Owned(bytes))
+    }
+}
+
+impl<'a> From<Base64Cow<'a>> for Cow<'a, [u8]> {
+    #[inline]
+    fn from(base64_cow: Base64Cow<'a>) -> Self {
+        base64_cow.0
+    }
+}
+
+impl serde_core::Serialize for Base64Cow<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        base64_cow::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de: 'a, 'a> serde_core::Deserialize<'de> for Base64Cow<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        base64_cow::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "schemars08")]
+mod schemars_impls {
+    use super::Base64Cow;
+    use alloc::string::String;
+    use schemars08::{
+        JsonSchema,
+        r#gen::SchemaGenerator,
+        schema::{InstanceType, Schema, SchemaObject},
+    };
+
+    impl JsonSchema for Base64Cow<'_> {
+        fn schema_name() -> String {
+            "Base64Cow".into()
+        }
+
+        fn is_referenceable() -> bool {
+            false
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            Schema::Object(SchemaObject {
+                instance_type: Some(InstanceType::String.into()),
+                format: Some("byte".into()),
+                extensions: [("contentEncoding".into(), "base64".into())]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+#[cfg(feature = "schemars1")]
+mod schemars1_impls {
+    use super::Base64Cow;
+    use alloc::borrow::Cow;
+    use schemars1::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    impl JsonSchema for Base64Cow<'_> {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            "Base64Cow".into()
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "format": "byte",
+                "contentEncoding": "base64",
+            })
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa_impls {
+    use super::Base64Cow;
+    use alloc::borrow::Cow;
+    use utoipa::{
+        PartialSchema, ToSchema,
+        openapi::{KnownFormat, ObjectBuilder, RefOr, Schema, SchemaFormat, Type},
+    };
+
+    impl PartialSchema for Base64Cow<'_> {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::KnownFormat(KnownFormat::Byte)))
+                .content_encoding("base64")
+                .into()
+        }
+    }
+
+    impl ToSchema for Base64Cow<'_> {
+        fn name() -> Cow<'static, str> {
+            "Base64Cow".into()
+        }
+    }
+}
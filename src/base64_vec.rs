@@ -2,11 +2,29 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Serialize a byte vector as base64 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use the standard, padded
+//! base64 alphabet from [RFC 4648 §4]. The submodules provide the same
+//! functions for other common variants:
+//!
+//! * [`standard_no_pad`]: the standard alphabet, without padding.
+//! * [`url_safe`]: the URL- and filename-safe alphabet from [RFC 4648 §5],
+//!   with padding.
+//! * [`url_safe_no_pad`]: the URL- and filename-safe alphabet, without
+//!   padding. This is the variant used by JWTs.
+//! * [`lenient`]: serializes as standard, padded base64, but accepts any of
+//!   the above when deserializing.
+//!
+//! [RFC 4648 §4]: https://datatracker.ietf.org/doc/html/rfc4648#section-4
+//! [RFC 4648 §5]: https://datatracker.ietf.org/doc/html/rfc4648#section-5
 
-use alloc::vec::Vec;
-use base64::Engine;
+use crate::encoding::{Base64, Encoding};
+use alloc::{vec, vec::Vec};
 use core::fmt;
-use serde_core::{Deserializer, Serializer, de::Visitor};
+use serde_core::{
+    Deserializer, Serializer,
+    de::{SeqAccess, Visitor},
+};
 
 /// Implements serialization for byte vectors to a base64 string if
 /// human-readable, or as bytes if not.
@@ -14,12 +32,7 @@ pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    if serializer.is_human_readable() {
-        let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
-        serializer.serialize_str(&encoded)
-    } else {
-        serializer.serialize_bytes(bytes)
-    }
+    crate::human_bytes_type::serialize::<Base64, S>(bytes, serializer)
 }
 
 /// Deserializes base64 strings (if human-readable) or byte arrays (if not) to
@@ -28,56 +41,347 @@ pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
 where
     D: Deserializer<'de>,
 {
-    use serde_core::de::Error;
+    crate::Base64Vec::deserialize(deserializer)
+}
 
+/// Like [`deserialize`], but rejects input that decodes to more than `MAX`
+/// bytes.
+///
+/// The length of base64 strings, padding included, is checked before they're
+/// decoded, so oversized strings are rejected without allocating for the
+/// decoded bytes.
+/// In non-human-readable formats, byte strings are checked before they're
+/// copied, though the format may already have allocated a buffer for them.
+/// Sequences of integers are rejected as soon as their length hint, or the
+/// number of elements read so far, is over `MAX`.
+pub fn deserialize_max<'de, D, const MAX: usize>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
     if deserializer.is_human_readable() {
-        struct Base64Visitor;
-
-        impl<'de2> Visitor<'de2> for Base64Visitor {
-            type Value = Vec<u8>;
-
-            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, "a base64-encoded string")
-            }
-
-            fn visit_str<E>(self, data: &str) -> Result<Self::Value, E>
-            where
-                E: Error,
-            {
-                base64::engine::general_purpose::STANDARD
-                    .decode(data)
-                    .map_err(Error::custom)
-            }
-
-            fn visit_borrowed_str<E>(self, data: &'de2 str) -> Result<Self::Value, E>
-            where
-                E: Error,
-            {
-                base64::engine::general_purpose::STANDARD
-                    .decode(data)
-                    .map_err(Error::custom)
-            }
+        deserializer.deserialize_str(BoundedBase64Visitor::<MAX>)
+    } else {
+        deserializer.deserialize_byte_buf(BoundedByteVecVisitor::<MAX>)
+    }
+}
+
+/// Decodes standard, padded base64 that decodes to at most `MAX` bytes.
+pub(crate) fn decode_max<const MAX: usize>(data: &str) -> Result<Vec<u8>, crate::DecodeError> {
+    // Check the length of the whole input first, padding included, so that
+    // a long run of `=` can't get past the check on the decoded length.
+    if data.len() > max_encoded_len::<MAX>() {
+        return Err(crate::DecodeError::TooLong {
+            max: MAX,
+            // The number of bytes that many symbols would decode to, written
+            // this way to avoid overflowing on very long input.
+            actual: data.len() / 4 * 3 + (data.len() % 4 * 3).div_ceil(4),
+        });
+    }
+    let len = Base64::decoded_len(data)?;
+    if len > MAX {
+        return Err(crate::DecodeError::TooLong {
+            max: MAX,
+            actual: len,
+        });
+    }
+    let mut out = vec![0u8; len];
+    Base64::decode_to_slice(data, &mut out)?;
+    Ok(out)
+}
+
+/// Returns the length of the padded base64 encoding of `MAX` bytes, which is
+/// the longest string that [`deserialize_max`] accepts.
+pub(crate) const fn max_encoded_len<const MAX: usize>() -> usize {
+    MAX.div_ceil(3).saturating_mul(4)
+}
+
+/// Serialization using the standard base64 alphabet, without padding.
+pub mod standard_no_pad {
+    use alloc::vec::Vec;
+    use serde_core::{Deserializer, Serializer};
+
+    /// Implements serialization for byte vectors to an unpadded base64 string
+    /// if human-readable, or as bytes if not.
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::human_bytes_type::serialize::<crate::encoding::Base64NoPad, S>(bytes, serializer)
+    }
+
+    /// Deserializes unpadded base64 strings (if human-readable) or byte arrays
+    /// (if not) to `Vec<u8>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::Base64NoPadVec::deserialize(deserializer)
+    }
+}
+
+/// Serialization using the URL-safe base64 alphabet, with padding.
+pub mod url_safe {
+    use alloc::vec::Vec;
+    use serde_core::{Deserializer, Serializer};
+
+    /// Implements serialization for byte vectors to a URL-safe base64 string if
+    /// human-readable, or as bytes if not.
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::human_bytes_type::serialize::<crate::encoding::Base64Url, S>(bytes, serializer)
+    }
+
+    /// Deserializes URL-safe base64 strings (if human-readable) or byte arrays
+    /// (if not) to `Vec<u8>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::Base64UrlVec::deserialize(deserializer)
+    }
+}
+
+/// Serialization using the URL-safe base64 alphabet, without padding.
+pub mod url_safe_no_pad {
+    use alloc::vec::Vec;
+    use serde_core::{Deserializer, Serializer};
+
+    /// Implements serialization for byte vectors to an unpadded URL-safe base64
+    /// string if human-readable, or as bytes if not.
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::human_bytes_type::serialize::<crate::encoding::Base64UrlNoPad, S>(bytes, serializer)
+    }
+
+    /// Deserializes unpadded URL-safe base64 strings (if human-readable) or
+    /// byte arrays (if not) to `Vec<u8>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::Base64UrlNoPadVec::deserialize(deserializer)
+    }
+}
+
+/// Serialization as standard base64, with lenient deserialization.
+///
+/// This is useful when accepting base64 produced by a variety of libraries:
+/// [`lenient::deserialize`] accepts both the standard and URL-safe alphabets,
+/// with or without padding. [`lenient::deserialize_ignore_whitespace`]
+/// additionally skips over ASCII whitespace, such as the line breaks in
+/// MIME-wrapped input.
+///
+/// Serialization always produces canonical, padded standard base64.
+pub mod lenient {
+    use crate::{
+        DecodeError,
+        byte_visitor::ByteVecVisitor,
+        encoding::{Base64Lenient, Encoding},
+    };
+    use alloc::{borrow::Cow, string::String, vec::Vec};
+    use base64::{
+        alphabet,
+        engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    };
+    use core::fmt;
+    use serde_core::{Deserializer, Serializer, de::Visitor};
+
+    const INDIFFERENT: GeneralPurposeConfig =
+        GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+    const STANDARD_INDIFFERENT: GeneralPurpose =
+        GeneralPurpose::new(&alphabet::STANDARD, INDIFFERENT);
+    const URL_SAFE_INDIFFERENT: GeneralPurpose =
+        GeneralPurpose::new(&alphabet::URL_SAFE, INDIFFERENT);
+
+    /// Implements serialization for byte vectors to a standard, padded base64
+    /// string if human-readable, or as bytes if not.
+    ///
+    /// This is the same as [`base64_vec::serialize`](super::serialize).
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::human_bytes_type::serialize::<Base64Lenient, S>(bytes, serializer)
+    }
+
+    /// Deserializes base64 strings in either the standard or URL-safe
+    /// alphabet, with or without padding (if human-readable), or byte arrays
+    /// (if not) to `Vec<u8>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::Base64LenientVec::deserialize(deserializer)
+    }
+
+    /// Like [`deserialize`], but also ignores any ASCII whitespace in the
+    /// input.
+    pub fn deserialize_ignore_whitespace<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(IgnoreWhitespaceVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(ByteVecVisitor)
         }
+    }
 
-        deserializer.deserialize_str(Base64Visitor)
-    } else {
-        struct BytesVisitor;
+    /// Returns the engine to decode `data` with.
+    pub(crate) fn engine_for(data: &str) -> &'static GeneralPurpose {
+        // The two alphabets only differ in their last two symbols, so the
+        // presence of either URL-safe symbol determines the alphabet. Input
+        // that mixes the two is rejected by the URL-safe engine.
+        if data.contains(['-', '_']) {
+            &URL_SAFE_INDIFFERENT
+        } else {
+            &STANDARD_INDIFFERENT
+        }
+    }
+
+    /// Visits a base64 string that may contain whitespace, decoding it with
+    /// [`Base64Lenient`].
+    struct IgnoreWhitespaceVisitor;
+
+    impl<'de> Visitor<'de> for IgnoreWhitespaceVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str(Base64Lenient::EXPECTING)
+        }
+
+        fn visit_str<E>(self, data: &str) -> Result<Self::Value, E>
+        where
+            E: serde_core::de::Error,
+        {
+            decode_ignore_whitespace(data).map_err(|error| error.into_de_error(&self))
+        }
+
+        fn visit_borrowed_str<E>(self, data: &'de str) -> Result<Self::Value, E>
+        where
+            E: serde_core::de::Error,
+        {
+            self.visit_str(data)
+        }
+    }
+
+    fn decode_ignore_whitespace(data: &str) -> Result<Vec<u8>, DecodeError> {
+        let stripped = if data.contains(|c: char| c.is_ascii_whitespace()) {
+            Cow::Owned(data.split_ascii_whitespace().collect::<String>())
+        } else {
+            Cow::Borrowed(data)
+        };
+        // Errors are reported against the original input, so map indexes in
+        // the stripped string back to it.
+        Base64Lenient::decode_vec(&stripped).map_err(|error| match error {
+            DecodeError::InvalidCharacter { character, index } => DecodeError::InvalidCharacter {
+                character,
+                index: original_index(data, index),
+            },
+            DecodeError::InvalidLastSymbol { character, index } => DecodeError::InvalidLastSymbol {
+                character,
+                index: original_index(data, index),
+            },
+            error => error,
+        })
+    }
+
+    /// Returns the index in `data` of the byte at `index` once whitespace is
+    /// removed.
+    fn original_index(data: &str, index: usize) -> usize {
+        data.bytes()
+            .enumerate()
+            .filter(|(_, b)| !b.is_ascii_whitespace())
+            .nth(index)
+            .map_or(data.len(), |(original, _)| original)
+    }
+}
 
-        impl<'de2> Visitor<'de2> for BytesVisitor {
-            type Value = Vec<u8>;
+/// Visits a base64 string of at most `MAX` bytes in human-readable formats.
+struct BoundedBase64Visitor<const MAX: usize>;
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "a byte array")
-            }
+impl<'de, const MAX: usize> Visitor<'de> for BoundedBase64Visitor<MAX> {
+    type Value = Vec<u8>;
 
-            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
-            where
-                E: Error,
-            {
-                Ok(v.to_vec())
-            }
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a base64-encoded string at most {} bytes long", MAX)
+    }
+
+    fn visit_str<E>(self, data: &str) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        decode_max::<MAX>(data).map_err(|error| error.into_de_error(&self))
+    }
+
+    fn visit_borrowed_str<E>(self, data: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        self.visit_str(data)
+    }
+}
+
+/// Visits a `Vec<u8>` of at most `MAX` bytes in non-human-readable formats.
+///
+/// Like [`ByteVecVisitor`], this accepts byte strings as well as sequences of
+/// integers.
+struct BoundedByteVecVisitor<const MAX: usize>;
+
+impl<const MAX: usize> BoundedByteVecVisitor<MAX> {
+    fn check_len<E>(&self, len: usize) -> Result<(), E>
+    where
+        E: serde_core::de::Error,
+    {
+        if len > MAX {
+            Err(E::invalid_length(len, self))
+        } else {
+            Ok(())
         }
+    }
+}
 
-        deserializer.deserialize_bytes(BytesVisitor)
+impl<'de, const MAX: usize> Visitor<'de> for BoundedByteVecVisitor<MAX> {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte array at most {} bytes long", MAX)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        self.check_len(v.len())?;
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde_core::de::Error,
+    {
+        self.check_len(v.len())?;
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        if let Some(len) = seq.size_hint() {
+            self.check_len(len)?;
+        }
+        let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            // Stop at the first element past `MAX`, rather than reading the
+            // rest of a sequence that may be arbitrarily long.
+            self.check_len(out.len() + 1)?;
+            out.push(byte);
+        }
+        Ok(out)
     }
 }
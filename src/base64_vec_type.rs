@@ -1,15 +1,14 @@
 // Copyright (c) The serde_human_bytes Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-//! The [`Base64Vec`] newtype wrapper.
+//! The [`Base64Vec`] type alias, and its siblings for other base64
+//! alphabets.
 
-extern crate alloc;
-
-use crate::base64_vec;
+use crate::{
+    HumanBytes,
+    encoding::{Base64, Base64Lenient, Base64NoPad, Base64Url, Base64UrlNoPad},
+};
 use alloc::vec::Vec;
-use base64::Engine;
-use core::fmt;
-use serde_core::{Deserializer, Serializer};
 
 /// A byte vector that serializes as base64 in human-readable formats.
 ///
@@ -18,147 +17,36 @@ use serde_core::{Deserializer, Serializer};
 /// 1. Directly as a field type, with serde impls built in.
 /// 2. With `#[serde(with = "Base64Vec")]` and `#[schemars(with = "Base64Vec")]`
 ///    on a `Vec<u8>` field.
-#[derive(Clone, PartialEq, Eq, Hash, Default)]
-pub struct Base64Vec(pub Vec<u8>);
-
-impl Base64Vec {
-    /// Creates a new `Base64Vec` from a byte vector.
-    #[inline]
-    pub fn new(bytes: Vec<u8>) -> Self {
-        Self(bytes)
-    }
-
-    /// Returns the inner byte vector.
-    #[inline]
-    pub fn into_inner(self) -> Vec<u8> {
-        self.0
-    }
-
-    /// Serializes a byte vector as base64 (for `#[serde(with = "Base64Vec")]`).
-    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        base64_vec::serialize(bytes, serializer)
-    }
-
-    /// Deserializes a byte vector from base64 (for `#[serde(with = "Base64Vec")]`).
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        base64_vec::deserialize(deserializer)
-    }
-}
-
-impl fmt::Debug for Base64Vec {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "Base64Vec({})",
-            base64::engine::general_purpose::STANDARD.encode(&self.0)
-        )
-    }
-}
-
-impl fmt::Display for Base64Vec {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        base64::engine::general_purpose::STANDARD
-            .encode(&self.0)
-            .fmt(f)
-    }
-}
-
-impl core::ops::Deref for Base64Vec {
-    type Target = Vec<u8>;
-
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl core::ops::DerefMut for Base64Vec {
-    #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
-impl AsRef<[u8]> for Base64Vec {
-    #[inline]
-    fn as_ref(&self) -> &[u8] {
-        &self.0
-    }
-}
-
-impl AsMut<[u8]> for Base64Vec {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [u8] {
-        &mut self.0
-    }
-}
-
-impl From<Vec<u8>> for Base64Vec {
-    #[inline]
-    fn from(bytes: Vec<u8>) -> Self {
-        Self(bytes)
-    }
-}
-
-impl From<Base64Vec> for Vec<u8> {
-    #[inline]
-    fn from(base64_vec: Base64Vec) -> Self {
-        base64_vec.0
-    }
-}
-
-impl serde_core::Serialize for Base64Vec {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        base64_vec::serialize(&self.0, serializer)
-    }
-}
-
-impl<'de> serde_core::Deserialize<'de> for Base64Vec {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        base64_vec::deserialize(deserializer).map(Self)
-    }
-}
+///
+/// This uses the standard, padded base64 alphabet. For other variants, see
+/// [`Base64NoPadVec`], [`Base64UrlVec`] and [`Base64UrlNoPadVec`].
+pub type Base64Vec = HumanBytes<Base64, Vec<u8>>;
 
-#[cfg(feature = "schemars08")]
-mod schemars_impls {
-    use super::Base64Vec;
-    use alloc::string::String;
-    use schemars08::{
-        JsonSchema,
-        r#gen::SchemaGenerator,
-        schema::{InstanceType, Schema, SchemaObject},
-    };
+/// A byte vector that serializes as unpadded base64 in human-readable
+/// formats.
+///
+/// This is the same as [`Base64Vec`], except that it omits trailing `=`
+/// padding when serializing, and rejects it when deserializing.
+pub type Base64NoPadVec = HumanBytes<Base64NoPad, Vec<u8>>;
 
-    impl JsonSchema for Base64Vec {
-        fn schema_name() -> String {
-            "Base64Vec".into()
-        }
+/// A byte vector that serializes as URL-safe base64 in human-readable
+/// formats.
+///
+/// This uses `-` and `_` in place of `+` and `/`, with padding.
+pub type Base64UrlVec = HumanBytes<Base64Url, Vec<u8>>;
 
-        fn is_referenceable() -> bool {
-            false
-        }
+/// A byte vector that serializes as unpadded URL-safe base64 in
+/// human-readable formats.
+///
+/// This uses `-` and `_` in place of `+` and `/`, without padding, as in
+/// JWTs.
+pub type Base64UrlNoPadVec = HumanBytes<Base64UrlNoPad, Vec<u8>>;
 
-        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
-            Schema::Object(SchemaObject {
-                instance_type: Some(InstanceType::String.into()),
-                format: Some("byte".into()),
-                extensions: [("contentEncoding".into(), "base64".into())]
-                    .into_iter()
-                    .collect(),
-                ..Default::default()
-            })
-        }
-    }
-}
+/// A byte vector that serializes as base64 in human-readable formats, and
+/// leniently accepts any common variant of base64 when deserializing.
+///
+/// This always serializes as standard, padded base64 (like [`Base64Vec`]),
+/// but accepts both the standard and URL-safe alphabets, with or without
+/// padding. See [`base64_vec::lenient`](crate::base64_vec::lenient) for
+/// details, and for a deserializer that also ignores embedded whitespace.
+pub type Base64LenientVec = HumanBytes<Base64Lenient, Vec<u8>>;
@@ -0,0 +1,63 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte array as base85 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use [Z85], ZeroMQ's base85,
+//! which only encodes arrays whose length is a multiple of 4. The [`ascii85`]
+//! submodule provides the same functions for Ascii85, which encodes any
+//! length. See [`encoding`](crate::encoding) for details of each.
+//!
+//! As with [`Z85Array`](crate::Z85Array), using the Z85 functions with an
+//! array whose length isn't a multiple of 4 is a compile-time error:
+//!
+//! ```compile_fail
+//! #[derive(serde::Serialize)]
+//! struct Key {
+//!     #[serde(with = "serde_human_bytes::base85_array")]
+//!     key: [u8; 3],
+//! }
+//!
+//! serde_json::to_string(&Key { key: [0; 3] }).unwrap();
+//! ```
+//!
+//! [Z85]: https://rfc.zeromq.org/spec/32/
+
+/// Defines `serialize` and `deserialize` functions for byte arrays in
+/// `$encoding`.
+macro_rules! base85_array_functions {
+    ($encoding:ty, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte arrays to ", $description, " if")]
+        #[doc = "human-readable, or as bytes if not."]
+        pub fn serialize<S, const N: usize>(
+            bytes: &[u8; N],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: serde_core::Serializer,
+        {
+            crate::HumanBytes::<$encoding, [u8; N]>::serialize(bytes, serializer)
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s (if human-readable) or byte arrays")]
+        #[doc = "(if not) to `[u8; N]`."]
+        #[doc = ""]
+        #[doc = "In self-describing binary formats such as CBOR, a sequence of exactly `N`"]
+        #[doc = "integers is also accepted, as produced by serde's own implementation for"]
+        #[doc = "`[u8; N]`. Formats that aren't self-describing, such as postcard and"]
+        #[doc = "bincode, only accept the length-prefixed bytes written by [`serialize`]."]
+        pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+        where
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<$encoding, [u8; N]>::deserialize(deserializer)
+        }
+    };
+}
+
+base85_array_functions!(crate::encoding::Z85, "a Z85 string");
+
+/// Serialization using Ascii85.
+pub mod ascii85 {
+    base85_array_functions!(crate::encoding::Ascii85, "an Ascii85 string");
+}
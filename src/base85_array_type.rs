@@ -0,0 +1,29 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Z85Array`] and [`Ascii85Array`] type aliases.
+
+use crate::{
+    HumanBytes,
+    encoding::{Ascii85, Z85},
+};
+
+/// A byte array that serializes as Z85 in human-readable formats.
+///
+/// Z85 only encodes multiples of 4 bytes, so `N` must be a multiple of 4.
+/// Other lengths are a compile-time error when the type is used:
+///
+/// ```compile_fail
+/// let array = serde_human_bytes::Z85Array::new([0u8; 5]);
+/// println!("{array:?}");
+/// ```
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Z85Array::<N>")]` and
+///    `#[schemars(with = "Z85Array<N>")]` on a `[u8; N]` field.
+pub type Z85Array<const N: usize> = HumanBytes<Z85, [u8; N]>;
+
+/// A byte array that serializes as Ascii85 in human-readable formats.
+pub type Ascii85Array<const N: usize> = HumanBytes<Ascii85, [u8; N]>;
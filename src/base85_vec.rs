@@ -0,0 +1,44 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte vector as base85 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use [Z85], ZeroMQ's base85,
+//! which only encodes vectors whose length is a multiple of 4. Other lengths
+//! fail to serialize with
+//! [`DecodeError::InvalidLengthMultiple`](crate::DecodeError::InvalidLengthMultiple).
+//! The [`ascii85`] submodule provides the same functions for Ascii85, which
+//! encodes any length. See [`encoding`](crate::encoding) for details of each.
+//!
+//! [Z85]: https://rfc.zeromq.org/spec/32/
+
+/// Defines `serialize` and `deserialize` functions for byte vectors in
+/// `$encoding`.
+macro_rules! base85_vec_functions {
+    ($encoding:ty, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte vectors to ", $description, " if")]
+        #[doc = "human-readable, or as bytes if not."]
+        pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde_core::Serializer,
+        {
+            crate::human_bytes_type::serialize::<$encoding, S>(bytes, serializer)
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s (if human-readable) or byte arrays")]
+        #[doc = "(if not) to `Vec<u8>`."]
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<alloc::vec::Vec<u8>, D::Error>
+        where
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<$encoding, alloc::vec::Vec<u8>>::deserialize(deserializer)
+        }
+    };
+}
+
+base85_vec_functions!(crate::encoding::Z85, "a Z85 string");
+
+/// Serialization using Ascii85.
+pub mod ascii85 {
+    base85_vec_functions!(crate::encoding::Ascii85, "an Ascii85 string");
+}
@@ -0,0 +1,26 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Z85Vec`] and [`Ascii85Vec`] type aliases.
+
+use crate::{
+    HumanBytes,
+    encoding::{Ascii85, Z85},
+};
+use alloc::vec::Vec;
+
+/// A byte vector that serializes as Z85 in human-readable formats.
+///
+/// Z85 only encodes multiples of 4 bytes. Other lengths fail to serialize as
+/// text with
+/// [`DecodeError::InvalidLengthMultiple`](crate::DecodeError::InvalidLengthMultiple).
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Z85Vec")]` and `#[schemars(with = "Z85Vec")]` on a
+///    `Vec<u8>` field.
+pub type Z85Vec = HumanBytes<Z85, Vec<u8>>;
+
+/// A byte vector that serializes as Ascii85 in human-readable formats.
+pub type Ascii85Vec = HumanBytes<Ascii85, Vec<u8>>;
@@ -0,0 +1,74 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte array as bech32 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use Bech32, and the
+//! [`bech32m`] submodule provides the same functions for Bech32m. See
+//! [`encoding`](crate::encoding) for details of each.
+//!
+//! The human-readable prefix is given by a [`Bech32Hrp`] marker type, which
+//! can't be inferred from the field. Name it with `serialize_with` and
+//! `deserialize_with`, along with the array length:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_human_bytes::encoding::Bech32Hrp;
+//!
+//! enum Npub {}
+//!
+//! impl Bech32Hrp for Npub {
+//!     const HRP: &'static str = "npub";
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Profile {
+//!     #[serde(
+//!         serialize_with = "serde_human_bytes::bech32_array::serialize::<Npub, _>",
+//!         deserialize_with = "serde_human_bytes::bech32_array::deserialize::<Npub, _, 32>"
+//!     )]
+//!     key: [u8; 32],
+//! }
+//! ```
+//!
+//! [`Bech32Hrp`]: crate::encoding::Bech32Hrp
+
+/// Defines `serialize` and `deserialize` functions for byte arrays in
+/// `$encoding`.
+macro_rules! bech32_array_functions {
+    ($encoding:ident, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte arrays to ", $description, " with the")]
+        #[doc = "prefix `H::HRP` if human-readable, or as bytes if not."]
+        pub fn serialize<H, S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            H: crate::encoding::Bech32Hrp,
+            S: serde_core::Serializer,
+        {
+            crate::human_bytes_type::serialize::<crate::encoding::$encoding<H>, S>(
+                bytes, serializer,
+            )
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s with the prefix `H::HRP` (if")]
+        #[doc = "human-readable) or byte arrays (if not) to `[u8; N]`."]
+        #[doc = ""]
+        #[doc = "In self-describing binary formats such as CBOR, a sequence of exactly `N`"]
+        #[doc = "integers is also accepted, as produced by serde's own implementation for"]
+        #[doc = "`[u8; N]`. Formats that aren't self-describing, such as postcard and"]
+        #[doc = "bincode, only accept the length-prefixed bytes written by [`serialize`]."]
+        pub fn deserialize<'de, H, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+        where
+            H: crate::encoding::Bech32Hrp,
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<crate::encoding::$encoding<H>, [u8; N]>::deserialize(deserializer)
+        }
+    };
+}
+
+bech32_array_functions!(Bech32, "a bech32 string");
+
+/// Serialization using Bech32m.
+pub mod bech32m {
+    bech32_array_functions!(Bech32m, "a bech32m string");
+}
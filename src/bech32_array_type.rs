@@ -0,0 +1,24 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Bech32Array`] and [`Bech32mArray`] type aliases.
+
+use crate::{
+    HumanBytes,
+    encoding::{Bech32, Bech32m},
+};
+
+/// A byte array that serializes as bech32 with the human-readable prefix
+/// `H::HRP` in human-readable formats.
+///
+/// See [`Bech32Hrp`](crate::encoding::Bech32Hrp) for how to define a prefix.
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Bech32Array::<H, N>")]` and
+///    `#[schemars(with = "Bech32Array<H, N>")]` on a `[u8; N]` field.
+pub type Bech32Array<H, const N: usize> = HumanBytes<Bech32<H>, [u8; N]>;
+
+/// A byte array that serializes as bech32m with the human-readable prefix
+/// `H::HRP` in human-readable formats.
+pub type Bech32mArray<H, const N: usize> = HumanBytes<Bech32m<H>, [u8; N]>;
@@ -0,0 +1,70 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte vector as bech32 if human-readable, or as bytes if not.
+//!
+//! The functions at the top level of this module use Bech32, and the
+//! [`bech32m`] submodule provides the same functions for Bech32m. See
+//! [`encoding`](crate::encoding) for details of each.
+//!
+//! As with [`bech32_array`](crate::bech32_array), the [`Bech32Hrp`] marker
+//! type must be named with `serialize_with` and `deserialize_with`:
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_human_bytes::encoding::Bech32Hrp;
+//!
+//! enum Lnbc {}
+//!
+//! impl Bech32Hrp for Lnbc {
+//!     const HRP: &'static str = "lnbc";
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Invoice {
+//!     #[serde(
+//!         serialize_with = "serde_human_bytes::bech32_vec::serialize::<Lnbc, _>",
+//!         deserialize_with = "serde_human_bytes::bech32_vec::deserialize::<Lnbc, _>"
+//!     )]
+//!     data: Vec<u8>,
+//! }
+//! ```
+//!
+//! [`Bech32Hrp`]: crate::encoding::Bech32Hrp
+
+/// Defines `serialize` and `deserialize` functions for byte vectors in
+/// `$encoding`.
+macro_rules! bech32_vec_functions {
+    ($encoding:ident, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte vectors to ", $description, " with the")]
+        #[doc = "prefix `H::HRP` if human-readable, or as bytes if not."]
+        pub fn serialize<H, S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            H: crate::encoding::Bech32Hrp,
+            S: serde_core::Serializer,
+        {
+            crate::human_bytes_type::serialize::<crate::encoding::$encoding<H>, S>(
+                bytes, serializer,
+            )
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s with the prefix `H::HRP` (if")]
+        #[doc = "human-readable) or byte arrays (if not) to `Vec<u8>`."]
+        pub fn deserialize<'de, H, D>(deserializer: D) -> Result<alloc::vec::Vec<u8>, D::Error>
+        where
+            H: crate::encoding::Bech32Hrp,
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<crate::encoding::$encoding<H>, alloc::vec::Vec<u8>>::deserialize(
+                deserializer,
+            )
+        }
+    };
+}
+
+bech32_vec_functions!(Bech32, "a bech32 string");
+
+/// Serialization using Bech32m.
+pub mod bech32m {
+    bech32_vec_functions!(Bech32m, "a bech32m string");
+}
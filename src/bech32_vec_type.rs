@@ -0,0 +1,25 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`Bech32Vec`] and [`Bech32mVec`] type aliases.
+
+use crate::{
+    HumanBytes,
+    encoding::{Bech32, Bech32m},
+};
+use alloc::vec::Vec;
+
+/// A byte vector that serializes as bech32 with the human-readable prefix
+/// `H::HRP` in human-readable formats.
+///
+/// See [`Bech32Hrp`](crate::encoding::Bech32Hrp) for how to define a prefix.
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "Bech32Vec::<H>")]` and
+///    `#[schemars(with = "Bech32Vec<H>")]` on a `Vec<u8>` field.
+pub type Bech32Vec<H> = HumanBytes<Bech32<H>, Vec<u8>>;
+
+/// A byte vector that serializes as bech32m with the human-readable prefix
+/// `H::HRP` in human-readable formats.
+pub type Bech32mVec<H> = HumanBytes<Bech32m<H>, Vec<u8>>;
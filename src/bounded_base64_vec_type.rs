@@ -0,0 +1,262 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`BoundedBase64Vec`] newtype wrapper.
+
+#[cfg(any(feature = "schemars08", feature = "schemars1", feature = "utoipa"))]
+use crate::base64_vec::max_encoded_len;
+use crate::{
+    DecodeError, base64_vec,
+    encoding::{Base64, Encoding},
+    human_bytes_type::pad_with,
+};
+use alloc::vec::Vec;
+use core::{fmt, str::FromStr};
+use serde_core::{Deserializer, Serializer};
+
+/// A byte vector of at most `MAX` bytes that serializes as base64 in
+/// human-readable formats.
+///
+/// This is the same as [`Base64Vec`](crate::Base64Vec), except that
+/// deserialization rejects input longer than `MAX` bytes, in most cases
+/// before decoding or copying it. See [`base64_vec::deserialize_max`] for
+/// details.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "BoundedBase64Vec::<MAX>")]` and
+///    `#[schemars(with = "BoundedBase64Vec<MAX>")]` on a `Vec<u8>` field.
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BoundedBase64Vec<const MAX: usize>(Vec<u8>);
+
+impl<const MAX: usize> BoundedBase64Vec<MAX> {
+    /// Creates a new `BoundedBase64Vec` from a byte vector, if it's at most
+    /// `MAX` bytes long.
+    pub fn new(bytes: Vec<u8>) -> Result<Self, DecodeError> {
+        if bytes.len() > MAX {
+            return Err(DecodeError::TooLong {
+                max: MAX,
+                actual: bytes.len(),
+            });
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Returns the inner byte vector.
+    #[inline]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Serializes a byte vector as base64 (for
+    /// `#[serde(with = "BoundedBase64Vec::<MAX>")]`).
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::human_bytes_type::serialize::<Base64, S>(bytes, serializer)
+    }
+
+    /// Deserializes a byte vector of at most `MAX` bytes from base64 (for
+    /// `#[serde(with = "BoundedBase64Vec::<MAX>")]`).
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        base64_vec::deserialize_max::<D, MAX>(deserializer)
+    }
+}
+
+impl<const MAX: usize> fmt::Debug for BoundedBase64Vec<MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("BoundedBase64Vec(")?;
+        Base64::encode(&self.0, f)?;
+        f.write_str(")")
+    }
+}
+
+impl<const MAX: usize> fmt::Display for BoundedBase64Vec<MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_with(f, |out| Base64::encode(&self.0, out))
+    }
+}
+
+// There's no `DerefMut`, since that would allow growing the vector past `MAX`.
+impl<const MAX: usize> core::ops::Deref for BoundedBase64Vec<MAX> {
+    type Target = Vec<u8>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MAX: usize> AsRef<[u8]> for BoundedBase64Vec<MAX> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const MAX: usize> AsMut<[u8]> for BoundedBase64Vec<MAX> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl<const MAX: usize> TryFrom<Vec<u8>> for BoundedBase64Vec<MAX> {
+    type Error = DecodeError;
+
+    #[inline]
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::new(bytes)
+    }
+}
+
+impl<const MAX: usize> TryFrom<&[u8]> for BoundedBase64Vec<MAX> {
+    type Error = DecodeError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() > MAX {
+            return Err(DecodeError::TooLong {
+                max: MAX,
+                actual: bytes.len(),
+            });
+        }
+        Ok(Self(bytes.to_vec()))
+    }
+}
+
+impl<const MAX: usize> From<BoundedBase64Vec<MAX>> for Vec<u8> {
+    #[inline]
+    fn from(value: BoundedBase64Vec<MAX>) -> Self {
+        value.0
+    }
+}
+
+/// Parses standard, padded base64 that decodes to at most `MAX` bytes.
+impl<const MAX: usize> FromStr for BoundedBase64Vec<MAX> {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        base64_vec::decode_max::<MAX>(s).map(Self)
+    }
+}
+
+impl<const MAX: usize> TryFrom<&str> for BoundedBase64Vec<MAX> {
+    type Error = DecodeError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const MAX: usize> serde_core::Serialize for BoundedBase64Vec<MAX> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, const MAX: usize> serde_core::Deserialize<'de> for BoundedBase64Vec<MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        base64_vec::deserialize_max::<D, MAX>(deserializer).map(Self)
+    }
+}
+
+#[cfg(feature = "schemars08")]
+mod schemars_impls {
+    use super::{BoundedBase64Vec, max_encoded_len};
+    use alloc::{boxed::Box, format, string::String};
+    use schemars08::{
+        JsonSchema,
+        r#gen::SchemaGenerator,
+        schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    };
+
+    impl<const MAX: usize> JsonSchema for BoundedBase64Vec<MAX> {
+        fn schema_name() -> String {
+            format!("BoundedBase64Vec_{MAX}")
+        }
+
+        fn is_referenceable() -> bool {
+            false
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            Schema::Object(SchemaObject {
+                instance_type: Some(InstanceType::String.into()),
+                format: Some("byte".into()),
+                string: Some(Box::new(StringValidation {
+                    max_length: Some(max_encoded_len::<MAX>() as u32),
+                    ..Default::default()
+                })),
+                extensions: [("contentEncoding".into(), "base64".into())]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+#[cfg(feature = "schemars1")]
+mod schemars1_impls {
+    use super::{BoundedBase64Vec, max_encoded_len};
+    use alloc::{borrow::Cow, format};
+    use schemars1::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    impl<const MAX: usize> JsonSchema for BoundedBase64Vec<MAX> {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            format!("BoundedBase64Vec_{MAX}").into()
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "type": "string",
+                "format": "byte",
+                "contentEncoding": "base64",
+                "maxLength": max_encoded_len::<MAX>(),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa_impls {
+    use super::{BoundedBase64Vec, max_encoded_len};
+    use alloc::{borrow::Cow, format};
+    use utoipa::{
+        PartialSchema, ToSchema,
+        openapi::{KnownFormat, ObjectBuilder, RefOr, Schema, SchemaFormat, Type},
+    };
+
+    impl<const MAX: usize> PartialSchema for BoundedBase64Vec<MAX> {
+        fn schema() -> RefOr<Schema> {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::KnownFormat(KnownFormat::Byte)))
+                .content_encoding("base64")
+                .max_length(Some(max_encoded_len::<MAX>()))
+                .into()
+        }
+    }
+
+    impl<const MAX: usize> ToSchema for BoundedBase64Vec<MAX> {
+        fn name() -> Cow<'static, str> {
+            format!("BoundedBase64Vec_{MAX}").into()
+        }
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Visitors for raw bytes, shared by the serialization modules and
+//! [`HumanBytes`](crate::HumanBytes) in non-human-readable formats.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{convert::TryInto, fmt};
+use serde_core::de::{Error, Expected, IgnoredAny, SeqAccess, Visitor};
+
+/// Visits a `[u8; N]` in non-human-readable formats.
+///
+/// In addition to byte strings, this accepts sequences of exactly `N`
+/// integers. That is how `[u8; N]` is represented by serde's own `Serialize`
+/// impl in self-describing formats such as CBOR, so data written with plain
+/// serde derives can be read back.
+///
+/// Formats that aren't self-describing, such as postcard, only call
+/// `visit_seq` when asked for a tuple, as [`hex_array::tuple`] does. Through
+/// `deserialize_bytes` they read a length-prefixed byte string instead.
+///
+/// [`hex_array::tuple`]: crate::hex_array::tuple
+pub(crate) struct ByteArrayVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for ByteArrayVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte array [u8; {}]", N)
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        v.try_into()
+            .map_err(|_| E::invalid_length(v.len(), &ByteArrayExpected::<N>))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_bytes(v)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_bytes(&v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut out = [0u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| A::Error::invalid_length(i, &ByteArrayExpected::<N>))?;
+        }
+
+        // Reject any trailing elements, counting them for the error message.
+        let mut len = N;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len != N {
+            return Err(A::Error::invalid_length(len, &ByteArrayExpected::<N>));
+        }
+
+        Ok(out)
+    }
+}
+
+pub(crate) struct ByteArrayExpected<const N: usize>;
+
+impl<const N: usize> Expected for ByteArrayExpected<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a byte array [u8; {}]", N)
+    }
+}
+
+/// Visits a `Vec<u8>` in non-human-readable formats.
+///
+/// This accepts byte strings, taking ownership of the buffer if the format
+/// provides one, as well as sequences of integers (serde's own representation
+/// for `Vec<u8>`).
+#[cfg(feature = "alloc")]
+pub(crate) struct ByteVecVisitor;
+
+#[cfg(feature = "alloc")]
+impl<'de> Visitor<'de> for ByteVecVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "a byte array")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Don't trust the size hint too far, since it comes from the input.
+        let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            out.push(byte);
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,654 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Text encodings for bytes in human-readable formats.
+//!
+//! An [`Encoding`] describes how to turn bytes into text and back. Combined
+//! with a byte container in [`HumanBytes`](crate::HumanBytes), it provides
+//! serde, `Display`, `FromStr` and schema implementations. The encodings
+//! built into this crate are:
+//!
+//! * [`Hex`]: lower-case hex, accepting either case.
+//! * [`HexUpper`]: upper-case hex, accepting either case.
+//! * [`HexStrict`] and [`HexUpperStrict`]: lower- and upper-case hex,
+//!   rejecting the other case.
+//! * [`PrefixedHex`]: lower-case hex with a `0x` prefix, accepting input
+//!   without it.
+//! * [`SeparatedHex`]: hex with a separator between groups of bytes, such as
+//!   `aa:bb:cc:dd:ee:ff`.
+//! * [`Base64`], [`Base64NoPad`], [`Base64Url`] and [`Base64UrlNoPad`]: the
+//!   base64 variants from [`base64_vec`](crate::base64_vec).
+//! * [`Base64Lenient`]: standard base64, accepting any of the above.
+//! * [`Base32`], [`Base32NoPad`], [`Base32Hex`], [`Base32HexNoPad`] and
+//!   [`Base32Crockford`]: the base32 variants from
+//!   [`base32_vec`](crate::base32_vec).
+//! * [`Z85`] and [`Ascii85`]: base85 variants from
+//!   [`base85_vec`](crate::base85_vec).
+//! * [`Base58`] and [`Base58Check`]: base58 with the Bitcoin alphabet, with
+//!   the `base58` feature.
+//! * [`Bech32`] and [`Bech32m`]: bech32 with a fixed human-readable prefix,
+//!   given by a [`Bech32Hrp`] marker type, with the `bech32` feature.
+//!
+//! To use a custom encoding, implement [`Encoding`] for a marker type and use
+//! `HumanBytes<MyEncoding, [u8; N]>` or `HumanBytes<MyEncoding, Vec<u8>>`.
+
+use crate::{DecodeError, hex_array::HexDisplay};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt;
+
+/// A text encoding for bytes.
+pub trait Encoding {
+    /// The name of the encoding, used as a prefix in `Debug` output and
+    /// schema names. For example, `"Hex"` for [`HexArray`](crate::HexArray).
+    const NAME: &'static str;
+
+    /// A description of the encoded string for error messages, such as
+    /// `"a hex-encoded string"`.
+    const EXPECTING: &'static str;
+
+    /// Writes the encoding of `bytes` to `out`.
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized;
+
+    /// The number of bytes that fixed-size arrays must be a multiple of, such
+    /// as 4 for [`Z85`].
+    ///
+    /// Using [`HumanBytes`](crate::HumanBytes) with an array of any other
+    /// length is a compile-time error. By default, this is 1.
+    const LEN_MULTIPLE: usize = 1;
+
+    /// Checks that `len` bytes can be encoded.
+    ///
+    /// Serializers call this before [`encode`](Self::encode), so that a
+    /// length the encoding can't represent is reported as a structured error
+    /// rather than a formatting failure. By default, any length is allowed.
+    fn check_len(len: usize) -> Result<(), DecodeError> {
+        let _ = len;
+        Ok(())
+    }
+
+    /// Returns the number of bytes that `data` decodes to.
+    ///
+    /// This should only fail if the length of `data` is invalid for the
+    /// encoding. Invalid symbols may instead be reported by
+    /// [`decode_to_slice`](Self::decode_to_slice).
+    fn decoded_len(data: &str) -> Result<usize, DecodeError>;
+
+    /// Decodes `data` into `out`, which is exactly
+    /// [`decoded_len`](Self::decoded_len) bytes long.
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError>;
+
+    /// Decodes `data` into `out`, failing with
+    /// [`DecodeError::InvalidLength`] if it doesn't decode to exactly
+    /// `out.len()` bytes.
+    ///
+    /// This is used for fixed-size arrays. By default, it checks
+    /// [`decoded_len`](Self::decoded_len) and then calls
+    /// [`decode_to_slice`](Self::decode_to_slice). Encodings whose decoded
+    /// length is expensive to find can override it to decode only once.
+    fn decode_exact(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        let len = Self::decoded_len(data)?;
+        if len != out.len() {
+            return Err(DecodeError::InvalidLength {
+                expected: out.len(),
+                actual: len,
+            });
+        }
+        Self::decode_to_slice(data, out)
+    }
+
+    /// Decodes `data` into a new `Vec<u8>`.
+    #[cfg(feature = "alloc")]
+    fn decode_vec(data: &str) -> Result<Vec<u8>, DecodeError> {
+        let mut out = vec![0u8; Self::decoded_len(data)?];
+        Self::decode_to_slice(data, &mut out)?;
+        Ok(out)
+    }
+
+    /// Returns the parameters of this encoding to append to schema names,
+    /// such as `_npub` in `Bech32Array_32_npub`.
+    ///
+    /// Schema names are used as keys for shared definitions, so an encoding
+    /// whose schema depends on type parameters must include them here to
+    /// keep the names distinct. By default, this is empty.
+    #[cfg(feature = "alloc")]
+    fn schema_name_suffix() -> String {
+        String::new()
+    }
+
+    /// Describes the JSON Schema for encoded strings, for the schemars and
+    /// utoipa implementations.
+    ///
+    /// `len` is the number of bytes for fixed-size arrays, or `None` for
+    /// vectors. By default, this describes any string.
+    #[cfg(feature = "alloc")]
+    fn schema(len: Option<usize>) -> StringSchema {
+        let _ = len;
+        StringSchema::default()
+    }
+}
+
+/// A description of a JSON Schema for strings, returned by
+/// [`Encoding::schema`].
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct StringSchema {
+    /// The `format` of the string, such as `"byte"`.
+    pub format: Option<&'static str>,
+    /// The `contentEncoding` of the string, such as `"base64"`.
+    pub content_encoding: Option<&'static str>,
+    /// The minimum length of the string.
+    pub min_length: Option<usize>,
+    /// The maximum length of the string.
+    pub max_length: Option<usize>,
+    /// A regular expression that the string matches.
+    pub pattern: Option<String>,
+}
+
+/// Lower-case hex. Decoding accepts either case.
+pub enum Hex {}
+
+impl Encoding for Hex {
+    const NAME: &'static str = "Hex";
+    const EXPECTING: &'static str = "a hex-encoded string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        write!(out, "{}", HexDisplay::lower(bytes))
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        if data.len() % 2 != 0 {
+            return Err(DecodeError::OddLength);
+        }
+        Ok(data.len() / 2)
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        hex::decode_to_slice(data, out)
+            .map_err(|error| DecodeError::from_hex(error, data, out.len()))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn schema(len: Option<usize>) -> StringSchema {
+        hex_schema("0-9a-fA-F", len)
+    }
+}
+
+/// Defines a hex encoding in one case, which delegates to [`Hex`] after
+/// checking the case of the input.
+macro_rules! hex_case_encoding {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $expecting:literal,
+        upper: $upper:literal,
+        strict: $strict:literal,
+        $symbols:literal $(,)?
+    ) => {
+        $(#[$attr])*
+        pub enum $name {}
+
+        impl Encoding for $name {
+            const NAME: &'static str = stringify!($name);
+            const EXPECTING: &'static str = $expecting;
+
+            fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+            where
+                W: fmt::Write + ?Sized,
+            {
+                let display = if $upper {
+                    HexDisplay::upper(bytes)
+                } else {
+                    HexDisplay::lower(bytes)
+                };
+                write!(out, "{display}")
+            }
+
+            fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+                Hex::decoded_len(data)
+            }
+
+            fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+                if $strict {
+                    check_hex_case(data, $upper)?;
+                }
+                Hex::decode_to_slice(data, out)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn schema(len: Option<usize>) -> StringSchema {
+                hex_schema($symbols, len)
+            }
+        }
+    };
+}
+
+hex_case_encoding!(
+    /// Upper-case hex. Decoding accepts either case.
+    ///
+    /// The schema describes the upper-case form that encoding produces.
+    HexUpper,
+    "a hex-encoded string",
+    upper: true,
+    strict: false,
+    "0-9A-F",
+);
+
+hex_case_encoding!(
+    /// Lower-case hex. Decoding rejects upper-case digits, so that only the
+    /// canonical form is accepted.
+    ///
+    /// An upper-case digit is reported as [`DecodeError::InvalidCharacter`].
+    HexStrict,
+    "a lower-case hex-encoded string",
+    upper: false,
+    strict: true,
+    "0-9a-f",
+);
+
+hex_case_encoding!(
+    /// Upper-case hex. Decoding rejects lower-case digits, so that only the
+    /// canonical form is accepted.
+    ///
+    /// A lower-case digit is reported as [`DecodeError::InvalidCharacter`].
+    HexUpperStrict,
+    "an upper-case hex-encoded string",
+    upper: true,
+    strict: true,
+    "0-9A-F",
+);
+
+/// Checks that every letter in `data` is in upper case if `upper` is true,
+/// and in lower case otherwise.
+///
+/// Other invalid characters are left for [`Hex`] to report.
+fn check_hex_case(data: &str, upper: bool) -> Result<(), DecodeError> {
+    let wrong_case = |c: char| {
+        if upper {
+            matches!(c, 'a'..='f')
+        } else {
+            matches!(c, 'A'..='F')
+        }
+    };
+    match data.char_indices().find(|&(_, c)| wrong_case(c)) {
+        Some((index, character)) => Err(DecodeError::InvalidCharacter { character, index }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the schema for hex with digits from the character class
+/// `symbols`.
+#[cfg(feature = "alloc")]
+fn hex_schema(symbols: &str, len: Option<usize>) -> StringSchema {
+    match len {
+        Some(len) => {
+            let hex_len = len * 2;
+            StringSchema {
+                min_length: Some(hex_len),
+                max_length: Some(hex_len),
+                pattern: Some(alloc::format!("^[{symbols}]{{{hex_len}}}$")),
+                ..Default::default()
+            }
+        }
+        None => StringSchema {
+            pattern: Some(alloc::format!("^([{symbols}]{{2}})*$")),
+            ..Default::default()
+        },
+    }
+}
+
+/// Lower-case hex with a `0x` prefix, as used by Ethereum JSON-RPC.
+///
+/// Decoding accepts either case, with or without the prefix. The prefix may
+/// also be written `0X`, and `0x` alone decodes to no bytes.
+///
+/// Decoding errors report character indexes in the whole input, including
+/// the prefix. [`DecodeError::OddLength`] and [`DecodeError::InvalidLength`]
+/// count hex digits and bytes, which the prefix doesn't add to, so they're
+/// the same with or without it.
+pub enum PrefixedHex {}
+
+impl Encoding for PrefixedHex {
+    const NAME: &'static str = "PrefixedHex";
+    const EXPECTING: &'static str = "a 0x-prefixed hex-encoded string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        write!(out, "0x{}", HexDisplay::lower(bytes))
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        Hex::decoded_len(strip_hex_prefix(data).1)
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        let (offset, digits) = strip_hex_prefix(data);
+        Hex::decode_to_slice(digits, out).map_err(|error| match error {
+            // Report indexes in the input, including the prefix.
+            DecodeError::InvalidCharacter { character, index } => DecodeError::InvalidCharacter {
+                character,
+                index: index + offset,
+            },
+            error => error,
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    fn schema(len: Option<usize>) -> StringSchema {
+        // This describes the prefixed form that encoding produces.
+        match len {
+            Some(len) => {
+                let hex_len = len * 2;
+                StringSchema {
+                    min_length: Some(hex_len + 2),
+                    max_length: Some(hex_len + 2),
+                    pattern: Some(alloc::format!("^0x[0-9a-fA-F]{{{hex_len}}}$")),
+                    ..Default::default()
+                }
+            }
+            None => StringSchema {
+                pattern: Some("^0x([0-9a-fA-F]{2})*$".into()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Returns `data` without a leading `0x` or `0X`, along with the length of
+/// the prefix.
+fn strip_hex_prefix(data: &str) -> (usize, &str) {
+    match data.strip_prefix("0x").or_else(|| data.strip_prefix("0X")) {
+        Some(digits) => (2, digits),
+        None => (0, data),
+    }
+}
+
+#[cfg(feature = "base58")]
+pub use self::base58::*;
+#[cfg(feature = "alloc")]
+pub use self::base64_encodings::*;
+#[cfg(feature = "bech32")]
+pub use self::bech32::*;
+pub use self::{base32::*, base85::*, separated_hex::*};
+
+mod base32;
+#[cfg(feature = "base58")]
+mod base58;
+mod base85;
+#[cfg(feature = "bech32")]
+mod bech32;
+mod separated_hex;
+
+#[cfg(feature = "alloc")]
+mod base64_encodings {
+    use super::{Encoding, StringSchema, fixed_pattern};
+    use crate::{DecodeError, base64_vec};
+    use base64::{
+        DecodeSliceError, Engine,
+        display::Base64Display,
+        engine::{
+            GeneralPurpose,
+            general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
+        },
+    };
+    use core::fmt;
+
+    /// Defines a base64 encoding that encodes with `$engine`, and decodes
+    /// with the engine returned by the function `$decode_engine`.
+    macro_rules! base64_encoding {
+        (
+            $(#[$attr:meta])*
+            $name:ident,
+            $expecting:literal,
+            $engine:expr,
+            $decode_engine:expr,
+            $schema:path $(,)?
+        ) => {
+            $(#[$attr])*
+            pub enum $name {}
+
+            impl Encoding for $name {
+                const NAME: &'static str = stringify!($name);
+                const EXPECTING: &'static str = $expecting;
+
+                fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+                where
+                    W: fmt::Write + ?Sized,
+                {
+                    write!(out, "{}", Base64Display::new(bytes, &$engine))
+                }
+
+                fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+                    base64_decoded_len(data)
+                }
+
+                fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+                    let decode_engine: fn(&str) -> &'static GeneralPurpose = $decode_engine;
+                    decode_slice(decode_engine(data), data, out)
+                }
+
+                fn schema(len: Option<usize>) -> StringSchema {
+                    $schema(len)
+                }
+            }
+        };
+    }
+
+    base64_encoding!(
+        /// Standard base64 from RFC 4648 §4, with padding.
+        Base64,
+        "a base64-encoded string",
+        STANDARD,
+        |_| &STANDARD,
+        padded_schema,
+    );
+
+    base64_encoding!(
+        /// Standard base64, without padding.
+        Base64NoPad,
+        "an unpadded base64-encoded string",
+        STANDARD_NO_PAD,
+        |_| &STANDARD_NO_PAD,
+        no_pad_schema,
+    );
+
+    base64_encoding!(
+        /// URL-safe base64 from RFC 4648 §5, with padding.
+        Base64Url,
+        "a URL-safe base64-encoded string",
+        URL_SAFE,
+        |_| &URL_SAFE,
+        url_schema,
+    );
+
+    base64_encoding!(
+        /// URL-safe base64, without padding.
+        Base64UrlNoPad,
+        "an unpadded URL-safe base64-encoded string",
+        URL_SAFE_NO_PAD,
+        |_| &URL_SAFE_NO_PAD,
+        url_no_pad_schema,
+    );
+
+    base64_encoding!(
+        /// Standard base64 with padding, accepting either alphabet with or
+        /// without padding when decoding. See
+        /// [`base64_vec::lenient`](crate::base64_vec::lenient).
+        Base64Lenient,
+        "a base64-encoded string",
+        STANDARD,
+        base64_vec::lenient::engine_for,
+        lenient_schema,
+    );
+
+    /// Returns the number of bytes that base64 `data` decodes to.
+    fn base64_decoded_len(data: &str) -> Result<usize, DecodeError> {
+        let unpadded = data.trim_end_matches('=').len();
+        if unpadded % 4 == 1 {
+            return Err(DecodeError::InvalidEncodedLength { length: data.len() });
+        }
+        // Written this way to avoid overflowing on very long input.
+        Ok(unpadded / 4 * 3 + unpadded % 4 * 3 / 4)
+    }
+
+    fn decode_slice(
+        engine: &GeneralPurpose,
+        data: &str,
+        out: &mut [u8],
+    ) -> Result<(), DecodeError> {
+        match engine.decode_slice(data, out) {
+            Ok(_) => Ok(()),
+            Err(DecodeSliceError::DecodeError(error)) => Err(DecodeError::from_base64(error, data)),
+            // `out` is large enough for any valid input, so the input must
+            // have been malformed in a way the engine didn't catch first.
+            Err(DecodeSliceError::OutputSliceTooSmall) => {
+                Err(DecodeError::InvalidEncodedLength { length: data.len() })
+            }
+        }
+    }
+
+    /// Returns the `(length, symbols, padding)` of the padded base64 encoding
+    /// of `len` bytes.
+    fn padded_len(len: usize) -> (usize, usize, usize) {
+        let encoded_len = len.div_ceil(3) * 4;
+        let padding = (3 - len % 3) % 3;
+        (encoded_len, encoded_len - padding, padding)
+    }
+
+    /// Returns the length of the unpadded base64 encoding of `len` bytes.
+    fn unpadded_len(len: usize) -> usize {
+        padded_len(len).1
+    }
+
+    fn padded_schema(len: Option<usize>) -> StringSchema {
+        let mut schema = StringSchema {
+            format: Some("byte"),
+            content_encoding: Some("base64"),
+            ..Default::default()
+        };
+        if let Some(len) = len {
+            let (encoded_len, symbols, padding) = padded_len(len);
+            schema.min_length = Some(encoded_len);
+            schema.max_length = Some(encoded_len);
+            schema.pattern = Some(fixed_pattern("A-Za-z0-9+/", symbols, padding));
+        }
+        schema
+    }
+
+    fn no_pad_schema(len: Option<usize>) -> StringSchema {
+        unpadded_schema(
+            "base64",
+            "A-Za-z0-9+/",
+            "^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2,3})?$",
+            len,
+        )
+    }
+
+    fn url_schema(len: Option<usize>) -> StringSchema {
+        let mut schema = StringSchema {
+            content_encoding: Some("base64url"),
+            ..Default::default()
+        };
+        match len {
+            Some(len) => {
+                let (encoded_len, symbols, padding) = padded_len(len);
+                schema.min_length = Some(encoded_len);
+                schema.max_length = Some(encoded_len);
+                schema.pattern = Some(fixed_pattern("A-Za-z0-9_-", symbols, padding));
+            }
+            None => {
+                schema.pattern =
+                    Some("^([A-Za-z0-9_-]{4})*([A-Za-z0-9_-]{2}==|[A-Za-z0-9_-]{3}=)?$".into());
+            }
+        }
+        schema
+    }
+
+    fn url_no_pad_schema(len: Option<usize>) -> StringSchema {
+        unpadded_schema(
+            "base64url",
+            "A-Za-z0-9_-",
+            "^([A-Za-z0-9_-]{4})*([A-Za-z0-9_-]{2,3})?$",
+            len,
+        )
+    }
+
+    fn lenient_schema(_len: Option<usize>) -> StringSchema {
+        // The length depends on the padding, so it isn't included even for
+        // arrays.
+        StringSchema {
+            content_encoding: Some("base64"),
+            pattern: Some("^[A-Za-z0-9+/_-]*={0,2}$".into()),
+            ..Default::default()
+        }
+    }
+
+    fn unpadded_schema(
+        content_encoding: &'static str,
+        symbols: &str,
+        vec_pattern: &str,
+        len: Option<usize>,
+    ) -> StringSchema {
+        let mut schema = StringSchema {
+            content_encoding: Some(content_encoding),
+            ..Default::default()
+        };
+        match len {
+            Some(len) => {
+                let encoded_len = unpadded_len(len);
+                schema.min_length = Some(encoded_len);
+                schema.max_length = Some(encoded_len);
+                schema.pattern = Some(fixed_pattern(symbols, encoded_len, 0));
+            }
+            None => schema.pattern = Some(vec_pattern.into()),
+        }
+        schema
+    }
+}
+
+/// Returns a pattern matching exactly `count` symbols from the character
+/// class `symbols`, followed by `padding` `=` characters.
+#[cfg(feature = "alloc")]
+fn fixed_pattern(symbols: &str, count: usize, padding: usize) -> String {
+    alloc::format!("^[{symbols}]{{{count}}}{}$", "=".repeat(padding))
+}
+
+/// Escapes `s` for use in a schema name, keeping ASCII letters and digits,
+/// and writing each byte of any other character as `-` and two hex digits.
+#[cfg(feature = "alloc")]
+fn escape_schema_name(s: &str) -> String {
+    use core::fmt::Write as _;
+
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            out.push(c);
+        } else {
+            for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                write!(out, "-{byte:02X}").expect("writing to a String succeeds");
+            }
+        }
+    }
+    out
+}
+
+/// Escapes characters in `s` that are special in regular expressions.
+#[cfg(feature = "alloc")]
+fn escape_regex(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\^$.|?*+()[]{}".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
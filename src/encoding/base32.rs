@@ -0,0 +1,359 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Base32 encodings from RFC 4648, and Crockford's base32.
+
+use super::Encoding;
+#[cfg(feature = "alloc")]
+use super::{StringSchema, fixed_pattern};
+use crate::DecodeError;
+use core::fmt;
+
+/// A base32 alphabet, and the rules for decoding it.
+struct Alphabet {
+    /// The symbols for each 5-bit value, in upper case.
+    symbols: &'static [u8; 32],
+    /// Decodes a symbol, or returns `None` if it isn't in the alphabet.
+    decode: fn(char) -> Option<u8>,
+    /// Whether encoded strings are padded with `=` to a multiple of 8.
+    padding: bool,
+    /// Whether `-` is ignored when decoding.
+    ignore_hyphens: bool,
+}
+
+const RFC4648: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const RFC4648_HEX: &[u8; 32] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+fn decode_rfc4648(c: char) -> Option<u8> {
+    match c {
+        'A'..='Z' => Some(c as u8 - b'A'),
+        'a'..='z' => Some(c as u8 - b'a'),
+        '2'..='7' => Some(c as u8 - b'2' + 26),
+        _ => None,
+    }
+}
+
+fn decode_rfc4648_hex(c: char) -> Option<u8> {
+    match c {
+        '0'..='9' => Some(c as u8 - b'0'),
+        'A'..='V' => Some(c as u8 - b'A' + 10),
+        'a'..='v' => Some(c as u8 - b'a' + 10),
+        _ => None,
+    }
+}
+
+fn decode_crockford(c: char) -> Option<u8> {
+    match c.to_ascii_uppercase() {
+        // These letters are easily mistaken for digits, so they decode as
+        // the digits they resemble.
+        'O' => Some(0),
+        'I' | 'L' => Some(1),
+        c => CROCKFORD
+            .iter()
+            .position(|&symbol| symbol as char == c)
+            .map(|value| value as u8),
+    }
+}
+
+const STANDARD: Alphabet = Alphabet {
+    symbols: RFC4648,
+    decode: decode_rfc4648,
+    padding: true,
+    ignore_hyphens: false,
+};
+
+const STANDARD_NO_PAD: Alphabet = Alphabet {
+    padding: false,
+    ..STANDARD
+};
+
+const HEX: Alphabet = Alphabet {
+    symbols: RFC4648_HEX,
+    decode: decode_rfc4648_hex,
+    padding: true,
+    ignore_hyphens: false,
+};
+
+const HEX_NO_PAD: Alphabet = Alphabet {
+    padding: false,
+    ..HEX
+};
+
+const CROCKFORD_ALPHABET: Alphabet = Alphabet {
+    symbols: CROCKFORD,
+    decode: decode_crockford,
+    padding: false,
+    ignore_hyphens: true,
+};
+
+impl Alphabet {
+    fn encode<W>(&self, bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        for chunk in bytes.chunks(5) {
+            let mut block = [0u8; 8];
+            block[3..3 + chunk.len()].copy_from_slice(chunk);
+            let value = u64::from_be_bytes(block);
+            let count = symbol_count(chunk.len());
+            for i in 0..count {
+                let index = (value >> (35 - 5 * i)) & 0x1f;
+                out.write_char(self.symbols[index as usize] as char)?;
+            }
+            if self.padding {
+                for _ in count..8 {
+                    out.write_char('=')?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `data` without padding, checking that the padding is correct.
+    fn strip_padding<'a>(&self, data: &'a str) -> Result<&'a str, DecodeError> {
+        let unpadded = data.trim_end_matches('=');
+        let symbols = self.symbol_len(unpadded);
+        if matches!(symbols % 8, 1 | 3 | 6) {
+            return Err(DecodeError::InvalidEncodedLength { length: data.len() });
+        }
+        let padding = data.len() - unpadded.len();
+        let expected_padding = if self.padding {
+            (8 - symbols % 8) % 8
+        } else {
+            0
+        };
+        if padding != expected_padding {
+            return Err(DecodeError::InvalidPadding);
+        }
+        Ok(unpadded)
+    }
+
+    /// Returns the number of symbols in `data`.
+    fn symbol_len(&self, data: &str) -> usize {
+        if self.ignore_hyphens {
+            data.bytes().filter(|&b| b != b'-').count()
+        } else {
+            data.len()
+        }
+    }
+
+    fn decoded_len(&self, data: &str) -> Result<usize, DecodeError> {
+        let symbols = self.symbol_len(self.strip_padding(data)?);
+        Ok(symbols / 8 * 5 + symbols % 8 * 5 / 8)
+    }
+
+    fn decode_to_slice(&self, data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        let data = self.strip_padding(data)?;
+        let mut buffer = 0u16;
+        let mut bits = 0;
+        let mut written = 0;
+        let mut last = None;
+        for (index, character) in data.char_indices() {
+            if self.ignore_hyphens && character == '-' {
+                continue;
+            }
+            let value = (self.decode)(character)
+                .ok_or(DecodeError::InvalidCharacter { character, index })?;
+            buffer = (buffer << 5) | u16::from(value);
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                // `out` is exactly `decoded_len` bytes long, so this only
+                // fails if the caller passed the wrong length.
+                let byte = out
+                    .get_mut(written)
+                    .ok_or(DecodeError::InvalidEncodedLength { length: data.len() })?;
+                *byte = (buffer >> bits) as u8;
+                written += 1;
+            }
+            buffer &= (1 << bits) - 1;
+            last = Some((character, index));
+        }
+        match last {
+            Some((character, index)) if buffer != 0 => {
+                Err(DecodeError::InvalidLastSymbol { character, index })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Returns the number of symbols needed to encode `len` bytes, without
+/// padding.
+const fn symbol_count(len: usize) -> usize {
+    (len * 8).div_ceil(5)
+}
+
+/// Defines a base32 encoding using `$alphabet`.
+macro_rules! base32_encoding {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $expecting:literal,
+        $alphabet:expr,
+        $schema:path $(,)?
+    ) => {
+        $(#[$attr])*
+        pub enum $name {}
+
+        impl Encoding for $name {
+            const NAME: &'static str = stringify!($name);
+            const EXPECTING: &'static str = $expecting;
+
+            fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+            where
+                W: fmt::Write + ?Sized,
+            {
+                $alphabet.encode(bytes, out)
+            }
+
+            fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+                $alphabet.decoded_len(data)
+            }
+
+            fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+                $alphabet.decode_to_slice(data, out)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn schema(len: Option<usize>) -> StringSchema {
+                $schema(len)
+            }
+        }
+    };
+}
+
+base32_encoding!(
+    /// Standard base32 from RFC 4648 §6, with padding.
+    ///
+    /// This encodes in upper case, and decodes either case.
+    Base32,
+    "a base32-encoded string",
+    STANDARD,
+    standard_schema,
+);
+
+base32_encoding!(
+    /// Standard base32, without padding.
+    ///
+    /// This is the form commonly used for TOTP secrets.
+    Base32NoPad,
+    "an unpadded base32-encoded string",
+    STANDARD_NO_PAD,
+    standard_no_pad_schema,
+);
+
+base32_encoding!(
+    /// Base32 with the extended hex alphabet from RFC 4648 §7, with padding.
+    ///
+    /// Unlike standard base32, this preserves the sort order of the encoded
+    /// bytes. It encodes in upper case, and decodes either case.
+    Base32Hex,
+    "a base32hex-encoded string",
+    HEX,
+    hex_schema,
+);
+
+base32_encoding!(
+    /// Base32 with the extended hex alphabet, without padding.
+    Base32HexNoPad,
+    "an unpadded base32hex-encoded string",
+    HEX_NO_PAD,
+    hex_no_pad_schema,
+);
+
+base32_encoding!(
+    /// Crockford's base32, without padding.
+    ///
+    /// This encodes in upper case. Decoding accepts either case, reads `I`
+    /// and `L` as `1` and `O` as `0`, and ignores hyphens. The optional check
+    /// symbol is not supported.
+    Base32Crockford,
+    "a Crockford base32-encoded string",
+    CROCKFORD_ALPHABET,
+    crockford_schema,
+);
+
+#[cfg(feature = "alloc")]
+const STANDARD_SYMBOLS: &str = "A-Za-z2-7";
+#[cfg(feature = "alloc")]
+const HEX_SYMBOLS: &str = "0-9A-Va-v";
+
+#[cfg(feature = "alloc")]
+fn standard_schema(len: Option<usize>) -> StringSchema {
+    let mut schema = padded_schema(STANDARD_SYMBOLS, len);
+    schema.content_encoding = Some("base32");
+    schema
+}
+
+#[cfg(feature = "alloc")]
+fn standard_no_pad_schema(len: Option<usize>) -> StringSchema {
+    let mut schema = unpadded_schema(STANDARD_SYMBOLS, len);
+    schema.content_encoding = Some("base32");
+    schema
+}
+
+#[cfg(feature = "alloc")]
+fn hex_schema(len: Option<usize>) -> StringSchema {
+    padded_schema(HEX_SYMBOLS, len)
+}
+
+#[cfg(feature = "alloc")]
+fn hex_no_pad_schema(len: Option<usize>) -> StringSchema {
+    unpadded_schema(HEX_SYMBOLS, len)
+}
+
+#[cfg(feature = "alloc")]
+fn crockford_schema(_len: Option<usize>) -> StringSchema {
+    // The length depends on the number of hyphens, so it isn't included even
+    // for arrays.
+    StringSchema {
+        pattern: Some("^[0-9A-TV-Za-tv-z-]*$".into()),
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn padded_schema(symbols: &str, len: Option<usize>) -> StringSchema {
+    match len {
+        Some(len) => {
+            let count = symbol_count(len);
+            let encoded_len = len.div_ceil(5) * 8;
+            StringSchema {
+                min_length: Some(encoded_len),
+                max_length: Some(encoded_len),
+                pattern: Some(fixed_pattern(symbols, count, encoded_len - count)),
+                ..Default::default()
+            }
+        }
+        None => StringSchema {
+            pattern: Some(alloc::format!(
+                "^([{symbols}]{{8}})*([{symbols}]{{2}}={{6}}|[{symbols}]{{4}}={{4}}|\
+                 [{symbols}]{{5}}={{3}}|[{symbols}]{{7}}=)?$"
+            )),
+            ..Default::default()
+        },
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn unpadded_schema(symbols: &str, len: Option<usize>) -> StringSchema {
+    match len {
+        Some(len) => {
+            let count = symbol_count(len);
+            StringSchema {
+                min_length: Some(count),
+                max_length: Some(count),
+                pattern: Some(fixed_pattern(symbols, count, 0)),
+                ..Default::default()
+            }
+        }
+        None => StringSchema {
+            pattern: Some(alloc::format!(
+                "^([{symbols}]{{8}})*([{symbols}]{{2}}|[{symbols}]{{4,5}}|[{symbols}]{{7}})?$"
+            )),
+            ..Default::default()
+        },
+    }
+}
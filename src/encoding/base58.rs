@@ -0,0 +1,166 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Base58 and Base58Check, with the Bitcoin alphabet.
+
+use super::{Encoding, StringSchema};
+use crate::DecodeError;
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+/// Base58 with the Bitcoin alphabet.
+///
+/// This omits `0`, `O`, `I` and `l`, which are easily confused. Leading zero
+/// bytes are encoded as leading `1`s.
+pub enum Base58 {}
+
+impl Encoding for Base58 {
+    const NAME: &'static str = "Base58";
+    const EXPECTING: &'static str = "a base58-encoded string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        out.write_str(&bs58::encode(bytes).into_string())
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        // The length depends on the value of the whole string, so this has
+        // to decode it.
+        Self::decode_vec(data).map(|bytes| bytes.len())
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        copy_decoded(&Self::decode_vec(data)?, out)
+    }
+
+    fn decode_exact(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        check_encoded_len(data, out.len())?;
+        let result = bs58::decode(data).onto(&mut *out);
+        finish_decode::<Self>(data, result, out.len())
+    }
+
+    fn decode_vec(data: &str) -> Result<Vec<u8>, DecodeError> {
+        bs58::decode(data)
+            .into_vec()
+            .map_err(|error| DecodeError::from_base58(error, data))
+    }
+
+    fn schema(_len: Option<usize>) -> StringSchema {
+        base58_schema()
+    }
+}
+
+/// Base58Check: base58 with a trailing 4-byte checksum, as used by Bitcoin
+/// addresses.
+///
+/// The checksum is the first 4 bytes of the double SHA-256 of the payload.
+/// It's appended when encoding, and verified and removed when decoding, with
+/// a mismatch reported as [`DecodeError::InvalidChecksum`]. Any version byte
+/// is part of the payload, and is not checked.
+pub enum Base58Check {}
+
+impl Encoding for Base58Check {
+    const NAME: &'static str = "Base58Check";
+    const EXPECTING: &'static str = "a Base58Check-encoded string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        out.write_str(&bs58::encode(bytes).with_check().into_string())
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        Self::decode_vec(data).map(|bytes| bytes.len())
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        copy_decoded(&Self::decode_vec(data)?, out)
+    }
+
+    fn decode_exact(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        check_encoded_len(data, out.len() + CHECKSUM_LEN)?;
+        // bs58 decodes the checksum along with the payload.
+        let mut buf = vec![0u8; out.len() + CHECKSUM_LEN];
+        let result = bs58::decode(data).with_check(None).onto(&mut buf[..]);
+        finish_decode::<Self>(data, result, out.len())?;
+        out.copy_from_slice(&buf[..out.len()]);
+        Ok(())
+    }
+
+    fn decode_vec(data: &str) -> Result<Vec<u8>, DecodeError> {
+        bs58::decode(data)
+            .with_check(None)
+            .into_vec()
+            .map_err(|error| DecodeError::from_base58(error, data))
+    }
+
+    fn schema(_len: Option<usize>) -> StringSchema {
+        base58_schema()
+    }
+}
+
+/// The length of the checksum that Base58Check appends.
+const CHECKSUM_LEN: usize = 4;
+
+/// Rejects strings too long to decode to `len` bytes, before decoding them.
+///
+/// Base58 decoding takes time quadratic in the length of the input, so this
+/// bounds the work done for untrusted input. The bound is loose: each byte
+/// needs fewer than 1.366 symbols (log 256 / log 58), and on top of that,
+/// this allows a leading `1` for every byte. Strings that are only a little
+/// too long are still decoded, so that the error says how many bytes they
+/// decode to.
+fn check_encoded_len(data: &str, len: usize) -> Result<(), DecodeError> {
+    let max = (len.saturating_mul(1366) / 1000 + 1).saturating_add(len);
+    if data.len() > max {
+        return Err(DecodeError::InvalidEncodedLength { length: data.len() });
+    }
+    Ok(())
+}
+
+/// Turns the result of decoding `data` into a buffer into the result for
+/// [`Encoding::decode_exact`], for an output of `len` bytes.
+fn finish_decode<E: Encoding>(
+    data: &str,
+    result: Result<usize, bs58::decode::Error>,
+    len: usize,
+) -> Result<(), DecodeError> {
+    match result {
+        Ok(actual) if actual == len => Ok(()),
+        Ok(actual) => Err(DecodeError::InvalidLength {
+            expected: len,
+            actual,
+        }),
+        // The input is short enough, thanks to `check_encoded_len`, that
+        // decoding it again to find the actual length for the error is cheap.
+        Err(bs58::decode::Error::BufferTooSmall) => Err(DecodeError::InvalidLength {
+            expected: len,
+            actual: E::decode_vec(data)?.len(),
+        }),
+        Err(error) => Err(DecodeError::from_base58(error, data)),
+    }
+}
+
+/// Copies decoded bytes into `out`, which should be the same length.
+fn copy_decoded(decoded: &[u8], out: &mut [u8]) -> Result<(), DecodeError> {
+    if decoded.len() != out.len() {
+        return Err(DecodeError::InvalidLength {
+            expected: out.len(),
+            actual: decoded.len(),
+        });
+    }
+    out.copy_from_slice(decoded);
+    Ok(())
+}
+
+fn base58_schema() -> StringSchema {
+    // The length depends on the value of the bytes, so it isn't included
+    // even for arrays.
+    StringSchema {
+        pattern: Some("^[1-9A-HJ-NP-Za-km-z]*$".into()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Base85 encodings: ZeroMQ's Z85, and Ascii85.
+
+use super::Encoding;
+#[cfg(feature = "alloc")]
+use super::{StringSchema, fixed_pattern};
+use crate::DecodeError;
+use core::fmt;
+
+/// A base85 alphabet, and the rules for decoding it.
+struct Alphabet {
+    /// The symbols for each value from 0 to 84.
+    symbols: &'static [u8; 85],
+    /// The value of each ASCII character, or `INVALID`.
+    values: [u8; 128],
+    /// Whether `z` stands for four zero bytes, and the input may end with a
+    /// partial group. Without this, the input must be a multiple of 4 bytes.
+    ascii85: bool,
+}
+
+const INVALID: u8 = 0xff;
+
+const Z85_SYMBOLS: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// `!` to `u`, in order.
+const ASCII85_SYMBOLS: &[u8; 85] = &{
+    let mut symbols = [0u8; 85];
+    let mut i = 0;
+    while i < 85 {
+        symbols[i] = b'!' + i as u8;
+        i += 1;
+    }
+    symbols
+};
+
+const fn values(symbols: &[u8; 85]) -> [u8; 128] {
+    let mut values = [INVALID; 128];
+    let mut i = 0;
+    while i < 85 {
+        values[symbols[i] as usize] = i as u8;
+        i += 1;
+    }
+    values
+}
+
+const Z85_ALPHABET: Alphabet = Alphabet {
+    symbols: Z85_SYMBOLS,
+    values: values(Z85_SYMBOLS),
+    ascii85: false,
+};
+
+const ASCII85_ALPHABET: Alphabet = Alphabet {
+    symbols: ASCII85_SYMBOLS,
+    values: values(ASCII85_SYMBOLS),
+    ascii85: true,
+};
+
+impl Alphabet {
+    fn check_len(&self, len: usize) -> Result<(), DecodeError> {
+        if !self.ascii85 && len % 4 != 0 {
+            return Err(DecodeError::InvalidLengthMultiple {
+                multiple: 4,
+                actual: len,
+            });
+        }
+        Ok(())
+    }
+
+    fn encode<W>(&self, bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        self.check_len(bytes.len()).map_err(|_| fmt::Error)?;
+        for chunk in bytes.chunks(4) {
+            if self.ascii85 && chunk == [0; 4] {
+                out.write_char('z')?;
+                continue;
+            }
+            let mut block = [0u8; 4];
+            block[..chunk.len()].copy_from_slice(chunk);
+            // A partial group of n bytes is encoded as the first n + 1
+            // symbols of the zero-padded group.
+            for &symbol in &self.encode_group(u32::from_be_bytes(block))[..chunk.len() + 1] {
+                out.write_char(symbol as char)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn encode_group(&self, mut value: u32) -> [u8; 5] {
+        let mut group = [0u8; 5];
+        for symbol in group.iter_mut().rev() {
+            *symbol = self.symbols[(value % 85) as usize];
+            value /= 85;
+        }
+        group
+    }
+
+    fn decoded_len(&self, data: &str) -> Result<usize, DecodeError> {
+        if !self.ascii85 {
+            if data.len() % 5 != 0 {
+                return Err(DecodeError::InvalidEncodedLength { length: data.len() });
+            }
+            return Ok(data.len() / 5 * 4);
+        }
+
+        let mut len = 0;
+        let mut count = 0;
+        for b in data.bytes() {
+            if b == b'z' && count == 0 {
+                len += 4;
+            } else {
+                count += 1;
+                if count == 5 {
+                    len += 4;
+                    count = 0;
+                }
+            }
+        }
+        match count {
+            0 => Ok(len),
+            // A single symbol can't encode a whole byte.
+            1 => Err(DecodeError::InvalidEncodedLength { length: data.len() }),
+            _ => Ok(len + count - 1),
+        }
+    }
+
+    fn decode_to_slice(&self, data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        let mut group = [0u8; 5];
+        let mut count = 0;
+        let mut group_start = 0;
+        let mut written = 0;
+        let mut last = None;
+        for (index, character) in data.char_indices() {
+            if self.ascii85 && character == 'z' && count == 0 {
+                write_bytes(out, &mut written, &[0; 4], data)?;
+                continue;
+            }
+            let value = self
+                .value(character)
+                .ok_or(DecodeError::InvalidCharacter { character, index })?;
+            if count == 0 {
+                group_start = index;
+            }
+            group[count] = value;
+            count += 1;
+            if count == 5 {
+                let value = group_value(&group).ok_or_else(|| overflow(data, group_start))?;
+                write_bytes(out, &mut written, &value.to_be_bytes(), data)?;
+                count = 0;
+            }
+            last = Some((character, index));
+        }
+
+        match (count, last) {
+            (0, _) => Ok(()),
+            (2.., Some((character, index))) if self.ascii85 => {
+                // Pad the partial group with the largest symbol, so that it
+                // rounds up to the bytes it encodes.
+                group[count..].fill(84);
+                let value = group_value(&group).ok_or_else(|| overflow(data, group_start))?;
+                let bytes = &value.to_be_bytes()[..count - 1];
+                // Any of several final symbols decode to the same bytes. Only
+                // accept the one that encoding produces.
+                let mut block = [0u8; 4];
+                block[..count - 1].copy_from_slice(bytes);
+                let canonical = self.encode_group(u32::from_be_bytes(block));
+                let matches = canonical[..count]
+                    .iter()
+                    .zip(&group[..count])
+                    .all(|(&symbol, &value)| symbol == self.symbols[usize::from(value)]);
+                if !matches {
+                    return Err(DecodeError::InvalidLastSymbol { character, index });
+                }
+                write_bytes(out, &mut written, bytes, data)
+            }
+            _ => Err(DecodeError::InvalidEncodedLength { length: data.len() }),
+        }
+    }
+
+    fn value(&self, character: char) -> Option<u8> {
+        let value = *self.values.get(character as usize)?;
+        (value != INVALID).then_some(value)
+    }
+}
+
+/// Returns the value of a group of 5 symbols, or `None` if it doesn't fit in
+/// 4 bytes.
+fn group_value(group: &[u8; 5]) -> Option<u32> {
+    let value = group
+        .iter()
+        .fold(0u64, |value, &symbol| value * 85 + u64::from(symbol));
+    u32::try_from(value).ok()
+}
+
+/// Reports a group whose value doesn't fit in 4 bytes, as an invalid
+/// character at the start of the group.
+fn overflow(data: &str, group_start: usize) -> DecodeError {
+    DecodeError::InvalidCharacter {
+        character: data[group_start..]
+            .chars()
+            .next()
+            .expect("group_start is the index of a character"),
+        index: group_start,
+    }
+}
+
+/// Writes `bytes` to `out` at `*written`.
+fn write_bytes(
+    out: &mut [u8],
+    written: &mut usize,
+    bytes: &[u8],
+    data: &str,
+) -> Result<(), DecodeError> {
+    // `out` is exactly `decoded_len` bytes long, so this only fails if the
+    // caller passed the wrong length.
+    out.get_mut(*written..*written + bytes.len())
+        .ok_or(DecodeError::InvalidEncodedLength { length: data.len() })?
+        .copy_from_slice(bytes);
+    *written += bytes.len();
+    Ok(())
+}
+
+/// Defines a base85 encoding using `$alphabet`.
+macro_rules! base85_encoding {
+    (
+        $(#[$attr:meta])*
+        $name:ident,
+        $expecting:literal,
+        $alphabet:expr,
+        $schema:path $(,)?
+    ) => {
+        $(#[$attr])*
+        pub enum $name {}
+
+        impl Encoding for $name {
+            const NAME: &'static str = stringify!($name);
+            const EXPECTING: &'static str = $expecting;
+            const LEN_MULTIPLE: usize = if $alphabet.ascii85 { 1 } else { 4 };
+
+            fn check_len(len: usize) -> Result<(), DecodeError> {
+                $alphabet.check_len(len)
+            }
+
+            fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+            where
+                W: fmt::Write + ?Sized,
+            {
+                $alphabet.encode(bytes, out)
+            }
+
+            fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+                $alphabet.decoded_len(data)
+            }
+
+            fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+                $alphabet.decode_to_slice(data, out)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn schema(len: Option<usize>) -> StringSchema {
+                $schema(len)
+            }
+        }
+    };
+}
+
+base85_encoding!(
+    /// [Z85], ZeroMQ's base85, which encodes every 4 bytes as 5 symbols.
+    ///
+    /// The alphabet avoids quotes and backslashes, so encoded strings don't
+    /// need escaping in JSON. Z85 can only encode a multiple of 4 bytes:
+    /// arrays of other lengths are a compile-time error, and vectors of other
+    /// lengths fail to serialize with [`DecodeError::InvalidLengthMultiple`].
+    /// `Debug` and `Display` show such vectors as `<invalid length N: hex>`.
+    /// Strings that aren't a multiple of 5 symbols are rejected with
+    /// [`DecodeError::InvalidEncodedLength`].
+    ///
+    /// [Z85]: https://rfc.zeromq.org/spec/32/
+    Z85,
+    "a Z85-encoded string",
+    Z85_ALPHABET,
+    z85_schema,
+);
+
+base85_encoding!(
+    /// [Ascii85], as used by `btoa` and PostScript, without the `<~` and
+    /// `~>` delimiters.
+    ///
+    /// Every 4 bytes are encoded as 5 symbols from `!` to `u`, or as `z` if
+    /// they're all zero. A final group of 1 to 3 bytes is encoded as 2 to 4
+    /// symbols. Whitespace is not allowed.
+    ///
+    /// The alphabet includes `"` and `\`, which JSON escapes. Prefer [`Z85`]
+    /// where that matters.
+    ///
+    /// [Ascii85]: https://en.wikipedia.org/wiki/Ascii85
+    Ascii85,
+    "an Ascii85-encoded string",
+    ASCII85_ALPHABET,
+    ascii85_schema,
+);
+
+#[cfg(feature = "alloc")]
+const Z85_CLASS: &str = r"0-9a-zA-Z.\-:+=^!/*?&<>()\[\]{}@%$#";
+
+#[cfg(feature = "alloc")]
+fn z85_schema(len: Option<usize>) -> StringSchema {
+    match len {
+        // `HumanBytes` only allows arrays whose length is a multiple of 4.
+        Some(len) => {
+            let count = len / 4 * 5;
+            StringSchema {
+                min_length: Some(count),
+                max_length: Some(count),
+                pattern: Some(fixed_pattern(Z85_CLASS, count, 0)),
+                ..Default::default()
+            }
+        }
+        None => StringSchema {
+            pattern: Some(alloc::format!("^([{Z85_CLASS}]{{5}})*$")),
+            ..Default::default()
+        },
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn ascii85_schema(len: Option<usize>) -> StringSchema {
+    // Groups of zeros are shortened to `z`, so only the maximum length is
+    // known for arrays.
+    let max_length = len.map(|len| match len % 4 {
+        0 => len / 4 * 5,
+        rem => len / 4 * 5 + rem + 1,
+    });
+    StringSchema {
+        max_length,
+        pattern: Some("^[!-uz]*$".into()),
+        ..Default::default()
+    }
+}
@@ -0,0 +1,335 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bech32 and Bech32m, with a fixed human-readable prefix.
+
+use super::Encoding;
+#[cfg(feature = "alloc")]
+use super::{StringSchema, escape_regex, escape_schema_name};
+use crate::{DecodeError, FoundPrefix};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use bech32::{
+    Checksum, Hrp,
+    primitives::decode::{CheckedHrpstring, CheckedHrpstringError, ChecksumError},
+};
+use core::{convert::Infallible, fmt, marker::PhantomData};
+
+/// The human-readable prefix of a bech32 string, such as `npub` for Nostr
+/// public keys or `age` for age recipients.
+///
+/// Implement this on a marker type to use with [`Bech32`] or [`Bech32m`]:
+///
+/// ```
+/// use serde_human_bytes::{Bech32Array, encoding::Bech32Hrp};
+///
+/// enum Npub {}
+///
+/// impl Bech32Hrp for Npub {
+///     const HRP: &'static str = "npub";
+/// }
+///
+/// type NostrPublicKey = Bech32Array<Npub, 32>;
+///
+/// let key = NostrPublicKey::new([0; 32]);
+/// assert!(key.to_string().starts_with("npub1"));
+/// ```
+pub trait Bech32Hrp {
+    /// The human-readable prefix, without the `1` separator.
+    ///
+    /// This must be 1 to 83 printable ASCII characters, all in the same case,
+    /// which is checked at compile time. Strings are encoded in lower case,
+    /// and decoded in either case.
+    const HRP: &'static str;
+}
+
+/// Bech32 from [BIP 173], with the human-readable prefix `H::HRP`.
+///
+/// Decoding checks the prefix, reporting a different one as
+/// [`DecodeError::InvalidPrefix`], and the checksum, reporting a mismatch as
+/// [`DecodeError::InvalidChecksum`].
+///
+/// The encoded string can be at most 1023 characters long, the longest that
+/// the checksum can protect. Serializing more bytes than fit fails with
+/// [`DecodeError::TooLong`]. `Debug` and `Display` show such values as
+/// `<invalid length N: hex>`.
+///
+/// Using a prefix that isn't valid, as described in [`Bech32Hrp::HRP`], is a
+/// compile-time error:
+///
+/// ```compile_fail
+/// use serde_human_bytes::{Bech32Array, encoding::Bech32Hrp};
+///
+/// enum MixedCase {}
+///
+/// impl Bech32Hrp for MixedCase {
+///     const HRP: &'static str = "Npub";
+/// }
+///
+/// println!("{}", Bech32Array::<MixedCase, 4>::new([0; 4]));
+/// ```
+///
+/// [BIP 173]: https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+pub struct Bech32<H>(Infallible, PhantomData<fn() -> H>);
+
+/// Bech32m from [BIP 350], with the human-readable prefix `H::HRP`.
+///
+/// This is the same as [`Bech32`] apart from the checksum, which fixes a
+/// weakness in Bech32's. Strings with a Bech32 checksum are rejected.
+///
+/// [BIP 350]: https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki
+pub struct Bech32m<H>(Infallible, PhantomData<fn() -> H>);
+
+impl<H: Bech32Hrp> Bech32<H> {
+    const VALID: () = assert!(
+        valid_hrp(H::HRP),
+        "the human-readable prefix must be 1 to 83 printable ASCII characters, all in the same case"
+    );
+}
+
+impl<H: Bech32Hrp> Encoding for Bech32<H> {
+    const NAME: &'static str = "Bech32";
+    const EXPECTING: &'static str = "a bech32-encoded string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let () = Self::VALID;
+        encode::<bech32::Bech32, W>(hrp::<H>(), bytes, out)
+    }
+
+    fn check_len(len: usize) -> Result<(), DecodeError> {
+        let () = Self::VALID;
+        check_len(H::HRP, len)
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        let () = Self::VALID;
+        parse::<bech32::Bech32>(H::HRP, data).map(|(_, len)| len)
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        let () = Self::VALID;
+        decode_to_slice::<bech32::Bech32>(H::HRP, data, out)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn schema_name_suffix() -> String {
+        alloc::format!("_{}", escape_schema_name(H::HRP))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn schema(len: Option<usize>) -> StringSchema {
+        let () = Self::VALID;
+        schema(H::HRP, len)
+    }
+}
+
+impl<H: Bech32Hrp> Bech32m<H> {
+    const VALID: () = assert!(
+        valid_hrp(H::HRP),
+        "the human-readable prefix must be 1 to 83 printable ASCII characters, all in the same case"
+    );
+}
+
+impl<H: Bech32Hrp> Encoding for Bech32m<H> {
+    const NAME: &'static str = "Bech32m";
+    const EXPECTING: &'static str = "a bech32m-encoded string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let () = Self::VALID;
+        encode::<bech32::Bech32m, W>(hrp::<H>(), bytes, out)
+    }
+
+    fn check_len(len: usize) -> Result<(), DecodeError> {
+        let () = Self::VALID;
+        check_len(H::HRP, len)
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        let () = Self::VALID;
+        parse::<bech32::Bech32m>(H::HRP, data).map(|(_, len)| len)
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        let () = Self::VALID;
+        decode_to_slice::<bech32::Bech32m>(H::HRP, data, out)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn schema_name_suffix() -> String {
+        alloc::format!("_{}", escape_schema_name(H::HRP))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn schema(len: Option<usize>) -> StringSchema {
+        let () = Self::VALID;
+        schema(H::HRP, len)
+    }
+}
+
+/// The bech32 alphabet, in the order of the values the symbols represent.
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The number of symbols in a bech32 checksum.
+const CHECKSUM_LEN: usize = 6;
+
+/// The length of the longest string that the checksums protect.
+const MAX_ENCODED_LEN: usize = 1023;
+
+/// Checks that `len` bytes fit in a string with the prefix `hrp`.
+fn check_len(hrp: &str, len: usize) -> Result<(), DecodeError> {
+    let symbols = MAX_ENCODED_LEN.saturating_sub(hrp.len() + 1 + CHECKSUM_LEN);
+    let max = symbols * 5 / 8;
+    if len > max {
+        return Err(DecodeError::TooLong { max, actual: len });
+    }
+    Ok(())
+}
+
+/// Returns whether `hrp` is a valid human-readable prefix: 1 to 83 printable
+/// ASCII characters, all in the same case.
+const fn valid_hrp(hrp: &str) -> bool {
+    let hrp = hrp.as_bytes();
+    if hrp.is_empty() || hrp.len() > 83 {
+        return false;
+    }
+    let (mut lower, mut upper) = (false, false);
+    let mut i = 0;
+    while i < hrp.len() {
+        let byte = hrp[i];
+        if byte < 33 || byte > 126 {
+            return false;
+        }
+        lower |= byte.is_ascii_lowercase();
+        upper |= byte.is_ascii_uppercase();
+        i += 1;
+    }
+    !(lower && upper)
+}
+
+fn hrp<H: Bech32Hrp>() -> Hrp {
+    Hrp::parse(H::HRP).expect("the prefix was checked at compile time")
+}
+
+fn encode<Ck, W>(hrp: Hrp, bytes: &[u8], mut out: &mut W) -> fmt::Result
+where
+    Ck: Checksum,
+    W: fmt::Write + ?Sized,
+{
+    bech32::encode_lower_to_fmt::<Ck, _>(&mut out, hrp, bytes).map_err(|_| fmt::Error)
+}
+
+/// Checks that `data` is a valid string with prefix `hrp` and checksum `Ck`,
+/// returning it along with the number of bytes it decodes to.
+fn parse<'s, Ck: Checksum>(
+    hrp: &'static str,
+    data: &'s str,
+) -> Result<(CheckedHrpstring<'s>, usize), DecodeError> {
+    // Check the prefix and characters first, since the bech32 crate doesn't
+    // report where invalid characters are.
+    let has_prefix = data
+        .get(..hrp.len())
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case(hrp))
+        && data.as_bytes().get(hrp.len()) == Some(&b'1');
+    if !has_prefix {
+        // The separator is the last `1`, since the data part can't contain
+        // one.
+        let found = data.rfind('1').map_or(data, |index| &data[..index]);
+        return Err(DecodeError::InvalidPrefix {
+            expected: hrp,
+            found: FoundPrefix::new(found),
+        });
+    }
+
+    let data_start = hrp.len() + 1;
+    let mut upper = None;
+    for (index, character) in data.char_indices() {
+        let in_charset = index < data_start
+            || (character.is_ascii() && CHARSET.contains(&(character.to_ascii_lowercase() as u8)));
+        // Either case is allowed, but not both.
+        let case_mismatch = character.is_ascii_alphabetic()
+            && *upper.get_or_insert(character.is_ascii_uppercase())
+                != character.is_ascii_uppercase();
+        if !in_charset || case_mismatch {
+            return Err(DecodeError::InvalidCharacter { character, index });
+        }
+    }
+
+    let checked = CheckedHrpstring::new::<Ck>(data).map_err(|error| match error {
+        CheckedHrpstringError::Checksum(ChecksumError::InvalidResidue) => {
+            DecodeError::InvalidChecksum
+        }
+        _ => DecodeError::InvalidEncodedLength { length: data.len() },
+    })?;
+
+    // Each symbol encodes 5 bits. Any bits left over after the last whole
+    // byte must be fewer than 5, and zero.
+    let symbols = checked.data_part_ascii_no_checksum();
+    let trailing_bits = symbols.len() * 5 % 8;
+    if trailing_bits >= 5 {
+        return Err(DecodeError::InvalidEncodedLength { length: data.len() });
+    }
+    if let Some(&last) = symbols.last() {
+        let value = CHARSET
+            .iter()
+            .position(|&c| c == last.to_ascii_lowercase())
+            .expect("characters were checked above");
+        if value & ((1 << trailing_bits) - 1) != 0 {
+            return Err(DecodeError::InvalidLastSymbol {
+                character: char::from(last),
+                index: data.len() - CHECKSUM_LEN - 1,
+            });
+        }
+    }
+
+    let len = symbols.len() * 5 / 8;
+    Ok((checked, len))
+}
+
+fn decode_to_slice<Ck: Checksum>(
+    hrp: &'static str,
+    data: &str,
+    out: &mut [u8],
+) -> Result<(), DecodeError> {
+    let (checked, len) = parse::<Ck>(hrp, data)?;
+    if len != out.len() {
+        return Err(DecodeError::InvalidLength {
+            expected: out.len(),
+            actual: len,
+        });
+    }
+    for (out, byte) in out.iter_mut().zip(checked.byte_iter()) {
+        *out = byte;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+fn schema(hrp: &str, len: Option<usize>) -> StringSchema {
+    let encoded_hrp_len = hrp.len() + 1;
+    // The pattern describes the canonical, lower-case form.
+    let hrp = escape_regex(&hrp.to_ascii_lowercase());
+    match len {
+        Some(len) => {
+            let symbols = (len * 8).div_ceil(5) + CHECKSUM_LEN;
+            let encoded_len = encoded_hrp_len + symbols;
+            StringSchema {
+                min_length: Some(encoded_len),
+                max_length: Some(encoded_len),
+                pattern: Some(alloc::format!("^{hrp}1[02-9ac-hj-np-z]{{{symbols}}}$")),
+                ..Default::default()
+            }
+        }
+        None => StringSchema {
+            pattern: Some(alloc::format!(
+                "^{hrp}1[02-9ac-hj-np-z]{{{CHECKSUM_LEN},}}$"
+            )),
+            ..Default::default()
+        },
+    }
+}
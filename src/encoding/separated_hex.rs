@@ -0,0 +1,168 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hex with a separator between groups of bytes.
+
+use super::Encoding;
+#[cfg(feature = "alloc")]
+use super::{StringSchema, escape_regex, escape_schema_name};
+use crate::{DecodeError, hex_array::HexDisplay};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{convert::Infallible, fmt};
+
+/// Hex with `SEP` between every `GROUP` bytes, such as `aa:bb:cc:dd:ee:ff`
+/// for a MAC address.
+///
+/// This encodes in upper case if `UPPER` is true, and lower case otherwise.
+/// Decoding accepts either case, but requires separators in exactly the
+/// places that encoding puts them.
+///
+/// The defaults are a colon between every byte, in lower case. Other common
+/// forms include:
+///
+/// * `SeparatedHex<'-', 1, true>`: the IEEE 802 form of MAC addresses, such
+///   as `AA-BB-CC-DD-EE-FF`.
+/// * `SeparatedHex<'.', 2>`: the form of MAC addresses used by some network
+///   equipment, such as `aabb.ccdd.eeff`.
+///
+/// A `GROUP` of zero, or a `SEP` that is a hex digit, is a compile-time
+/// error when the encoding is used.
+pub struct SeparatedHex<const SEP: char = ':', const GROUP: usize = 1, const UPPER: bool = false>(
+    Infallible,
+);
+
+impl<const SEP: char, const GROUP: usize, const UPPER: bool> SeparatedHex<SEP, GROUP, UPPER> {
+    const VALID: () = assert!(
+        GROUP > 0 && !SEP.is_ascii_hexdigit(),
+        "the group size must be non-zero, and the separator must not be a hex digit"
+    );
+}
+
+impl<const SEP: char, const GROUP: usize, const UPPER: bool> Encoding
+    for SeparatedHex<SEP, GROUP, UPPER>
+{
+    const NAME: &'static str = "SeparatedHex";
+    const EXPECTING: &'static str = "a separated hex-encoded string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        let () = Self::VALID;
+        for (i, chunk) in bytes.chunks(GROUP).enumerate() {
+            if i > 0 {
+                out.write_char(SEP)?;
+            }
+            let display = if UPPER {
+                HexDisplay::upper(chunk)
+            } else {
+                HexDisplay::lower(chunk)
+            };
+            write!(out, "{display}")?;
+        }
+        Ok(())
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        let () = Self::VALID;
+        if data.is_empty() {
+            return Ok(0);
+        }
+        // Each whole group is 2 * GROUP digits and a separator, counting one
+        // after the last group too.
+        let sep_len = SEP.len_utf8();
+        let group_len = 2 * GROUP + sep_len;
+        let groups = (data.len() + sep_len) / group_len;
+        match (data.len() + sep_len) % group_len {
+            0 => Ok(groups * GROUP),
+            // A final partial group of 1 to GROUP - 1 bytes.
+            rem if rem > sep_len && (rem - sep_len) % 2 == 0 => {
+                Ok(groups * GROUP + (rem - sep_len) / 2)
+            }
+            _ => Err(DecodeError::InvalidEncodedLength { length: data.len() }),
+        }
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        let mut digits = 0;
+        let mut written = 0;
+        for (index, character) in data.char_indices() {
+            let invalid = DecodeError::InvalidCharacter { character, index };
+            if digits == 2 * GROUP {
+                if character != SEP {
+                    return Err(invalid);
+                }
+                digits = 0;
+                continue;
+            }
+            let value = character.to_digit(16).ok_or(invalid)? as u8;
+            // `out` is exactly `decoded_len` bytes long, so this only fails
+            // if the caller passed the wrong length.
+            let byte = out
+                .get_mut(written)
+                .ok_or(DecodeError::InvalidEncodedLength { length: data.len() })?;
+            if digits % 2 == 0 {
+                *byte = value << 4;
+            } else {
+                *byte |= value;
+                written += 1;
+            }
+            digits += 1;
+        }
+        if written != out.len() || digits % 2 != 0 {
+            return Err(DecodeError::InvalidEncodedLength { length: data.len() });
+        }
+        Ok(())
+    }
+
+    /// The separator, group size and case, such as `_-3A_1_lower` for the
+    /// defaults. The separator is escaped as its UTF-8 bytes in hex.
+    #[cfg(feature = "alloc")]
+    fn schema_name_suffix() -> String {
+        let sep = escape_schema_name(SEP.encode_utf8(&mut [0; 4]));
+        let case = if UPPER { "upper" } else { "lower" };
+        alloc::format!("_{sep}_{GROUP}_{case}")
+    }
+
+    #[cfg(feature = "alloc")]
+    fn schema(len: Option<usize>) -> StringSchema {
+        let () = Self::VALID;
+        let sep = escape_regex(SEP.encode_utf8(&mut [0; 4]));
+        let group = 2 * GROUP;
+        match len {
+            Some(0) => StringSchema {
+                min_length: Some(0),
+                max_length: Some(0),
+                pattern: Some("^$".into()),
+                ..Default::default()
+            },
+            Some(len) => {
+                let groups = len.div_ceil(GROUP);
+                let encoded_len = 2 * len + groups - 1;
+                // All groups but the last are whole.
+                let last = 2 * (len - (groups - 1) * GROUP);
+                let pattern = if groups == 1 {
+                    alloc::format!("^[0-9a-fA-F]{{{last}}}$")
+                } else {
+                    alloc::format!(
+                        "^([0-9a-fA-F]{{{group}}}{sep}){{{}}}[0-9a-fA-F]{{{last}}}$",
+                        groups - 1
+                    )
+                };
+                StringSchema {
+                    min_length: Some(encoded_len),
+                    max_length: Some(encoded_len),
+                    pattern: Some(pattern),
+                    ..Default::default()
+                }
+            }
+            None => StringSchema {
+                pattern: Some(alloc::format!(
+                    "^(([0-9a-fA-F]{{{group}}}{sep})*([0-9a-fA-F]{{2}}){{1,{GROUP}}})?$"
+                )),
+                ..Default::default()
+            },
+        }
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Errors returned when decoding text into bytes.
+
+use core::fmt;
+use serde_core::de::Expected;
+
+/// An error that occurred while decoding a string into bytes.
+///
+/// This is returned by the `FromStr` and `TryFrom` implementations on the
+/// types in this crate. Deserializers report the same errors through
+/// [`serde_core::de::Error`], so their messages match this type's `Display`
+/// output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DecodeError {
+    /// The input contained a character that is not valid for the encoding.
+    InvalidCharacter {
+        /// The invalid character.
+        character: char,
+        /// The byte offset of the character in the input.
+        index: usize,
+    },
+
+    /// The input had an odd number of hex digits.
+    OddLength,
+
+    /// The input decoded to the wrong number of bytes.
+    InvalidLength {
+        /// The number of bytes expected.
+        expected: usize,
+        /// The number of bytes in the input.
+        actual: usize,
+    },
+
+    /// The number of bytes is not a multiple of the encoding's block size.
+    /// For example, Z85 can only encode multiples of 4 bytes.
+    InvalidLengthMultiple {
+        /// The block size, in bytes.
+        multiple: usize,
+        /// The number of bytes in the input.
+        actual: usize,
+    },
+
+    /// The input decoded to more bytes than allowed.
+    TooLong {
+        /// The maximum number of bytes allowed.
+        max: usize,
+        /// The number of bytes in the input.
+        actual: usize,
+    },
+
+    /// The number of symbols in the input is not valid for the encoding. For
+    /// example, a single trailing base64 symbol can't encode a whole byte.
+    InvalidEncodedLength {
+        /// The number of symbols in the input.
+        length: usize,
+    },
+
+    /// The last symbol in the input has non-zero trailing bits, so the input
+    /// is not the canonical encoding of any byte sequence.
+    InvalidLastSymbol {
+        /// The invalid symbol.
+        character: char,
+        /// The byte offset of the symbol in the input.
+        index: usize,
+    },
+
+    /// The input had missing, extraneous or misplaced padding.
+    InvalidPadding,
+
+    /// The checksum in the input did not match the rest of the input, or the
+    /// input was too short to contain a checksum.
+    InvalidChecksum,
+
+    /// The input did not start with the expected bech32 human-readable
+    /// prefix, followed by the `1` separator.
+    InvalidPrefix {
+        /// The expected human-readable prefix, without the separator.
+        expected: &'static str,
+        /// The prefix that was found: everything before the last `1`, or the
+        /// whole input if there is no `1`.
+        found: FoundPrefix,
+    },
+}
+
+/// The prefix found in a bech32 string, as reported by
+/// [`DecodeError::InvalidPrefix`].
+///
+/// This holds up to 83 bytes, the length of the longest valid prefix, so that
+/// it doesn't need `alloc`. Anything longer is truncated.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FoundPrefix {
+    bytes: [u8; FoundPrefix::CAPACITY],
+    len: u8,
+}
+
+impl FoundPrefix {
+    const CAPACITY: usize = 83;
+
+    /// Creates a new `FoundPrefix`, truncating `prefix` to at most 83 bytes
+    /// at a character boundary.
+    pub fn new(prefix: &str) -> Self {
+        let mut len = prefix.len().min(Self::CAPACITY);
+        while !prefix.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut bytes = [0u8; Self::CAPACITY];
+        bytes[..len].copy_from_slice(&prefix.as_bytes()[..len]);
+        Self {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    /// Returns the prefix as a string.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)])
+            .expect("truncated at a character boundary")
+    }
+}
+
+impl fmt::Debug for FoundPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl fmt::Display for FoundPrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl DecodeError {
+    /// Converts an error from decoding hex into a `[u8]` of length `expected`.
+    pub(crate) fn from_hex(error: hex::FromHexError, input: &str, expected: usize) -> Self {
+        match error {
+            hex::FromHexError::InvalidHexCharacter { c, index } => Self::InvalidCharacter {
+                character: char_at(input, index).unwrap_or(c),
+                index,
+            },
+            hex::FromHexError::OddLength => Self::OddLength,
+            hex::FromHexError::InvalidStringLength => Self::InvalidLength {
+                expected,
+                actual: input.len() / 2,
+            },
+        }
+    }
+
+    /// Converts this error into a deserializer error.
+    ///
+    /// Length mismatches are reported via [`Error::invalid_length`], the same
+    /// way as a byte array of the wrong length in non-human-readable formats.
+    /// Other errors keep their structured message.
+    ///
+    /// [`Error::invalid_length`]: serde_core::de::Error::invalid_length
+    pub(crate) fn into_de_error<E>(self, expected: &dyn Expected) -> E
+    where
+        E: serde_core::de::Error,
+    {
+        match self {
+            Self::InvalidLength { actual, .. } | Self::TooLong { actual, .. } => {
+                E::invalid_length(actual, expected)
+            }
+            error => E::custom(error),
+        }
+    }
+
+    /// Converts an error from decoding base64.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_base64(error: base64::DecodeError, input: &str) -> Self {
+        match error {
+            base64::DecodeError::InvalidByte(index, byte) => Self::InvalidCharacter {
+                character: char_at(input, index).unwrap_or(char::from(byte)),
+                index,
+            },
+            base64::DecodeError::InvalidLength(length) => Self::InvalidEncodedLength { length },
+            base64::DecodeError::InvalidLastSymbol(index, byte) => Self::InvalidLastSymbol {
+                character: char_at(input, index).unwrap_or(char::from(byte)),
+                index,
+            },
+            base64::DecodeError::InvalidPadding => Self::InvalidPadding,
+        }
+    }
+
+    /// Converts an error from decoding base58 or Base58Check.
+    #[cfg(feature = "base58")]
+    pub(crate) fn from_base58(error: bs58::decode::Error, input: &str) -> Self {
+        match error {
+            bs58::decode::Error::InvalidCharacter { character, index } => {
+                Self::InvalidCharacter { character, index }
+            }
+            bs58::decode::Error::NonAsciiCharacter { index } => Self::InvalidCharacter {
+                character: char_at(input, index).unwrap_or(char::REPLACEMENT_CHARACTER),
+                index,
+            },
+            bs58::decode::Error::InvalidChecksum { .. } | bs58::decode::Error::NoChecksum => {
+                Self::InvalidChecksum
+            }
+            // We always decode into a vector, and never check the version
+            // byte, so other errors can't happen.
+            _ => Self::InvalidEncodedLength {
+                length: input.len(),
+            },
+        }
+    }
+}
+
+/// Returns the character starting at byte offset `index`, if any.
+///
+/// The decoders we wrap report invalid input a byte at a time, which for
+/// non-ASCII input isn't the character the user actually wrote.
+fn char_at(input: &str, index: usize) -> Option<char> {
+    input.get(index..)?.chars().next()
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCharacter { character, index } => {
+                write!(f, "invalid character {character:?} at index {index}")
+            }
+            Self::OddLength => write!(f, "odd number of hex digits"),
+            Self::InvalidLength { expected, actual } => {
+                write!(f, "invalid length {actual}, expected {expected} bytes")
+            }
+            Self::InvalidLengthMultiple { multiple, actual } => {
+                write!(
+                    f,
+                    "invalid length {actual}, expected a multiple of {multiple} bytes"
+                )
+            }
+            Self::TooLong { max, actual } => {
+                write!(f, "invalid length {actual}, expected at most {max} bytes")
+            }
+            Self::InvalidEncodedLength { length } => {
+                write!(f, "invalid encoded length {length}")
+            }
+            Self::InvalidLastSymbol { character, index } => {
+                write!(
+                    f,
+                    "invalid last symbol {character:?} at index {index}: \
+                     trailing bits must be zero"
+                )
+            }
+            Self::InvalidPadding => write!(f, "invalid padding"),
+            Self::InvalidChecksum => write!(f, "invalid checksum"),
+            Self::InvalidPrefix { expected, found } => {
+                write!(f, "invalid prefix {found:?}, expected {expected:?}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
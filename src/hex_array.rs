@@ -3,116 +3,202 @@
 
 //! Serialize a byte array as a list of bytes if human-readable, or as hex if not.
 
-use core::{convert::TryInto, fmt};
-use serde_core::{
-    Deserializer,
-    de::{Expected, Visitor},
-};
-
-/// Serialization implementations that require the `alloc` feature.
-#[cfg(feature = "alloc")]
-mod alloc_impls {
-    use hex::ToHex;
-    use serde_core::Serializer;
-
-    /// Implements serialization for byte arrays to a hex string if human-readable, or as bytes if not.
+use core::fmt;
+use serde_core::{Deserializer, Serializer};
+
+/// Implements serialization for byte arrays to a hex string if human-readable, or as bytes if not.
+///
+/// This should work transparently with any `[u8; N]`.
+///
+/// This does not require `alloc`: in human-readable formats, the hex string
+/// is streamed to the serializer via [`Serializer::collect_str`].
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::human_bytes_type::serialize::<crate::encoding::Hex, S>(bytes, serializer)
+}
+
+/// Similar to [`serialize`], except to upper-case.
+pub fn serialize_upper<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::human_bytes_type::serialize::<crate::encoding::HexUpper, S>(bytes, serializer)
+}
+
+/// Deserializes hex strings (if human-readable) or byte arrays (if not) to `[u8; N]`.
+///
+/// In self-describing binary formats such as CBOR, a sequence of exactly `N`
+/// integers is also accepted, as produced by serde's own implementation for
+/// `[u8; N]`. Formats that aren't self-describing, such as postcard and
+/// bincode, only accept the length-prefixed bytes written by [`serialize`];
+/// use [`tuple`](mod@tuple) to read the bare bytes that serde writes for `[u8; N]`
+/// there.
+pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::HexArray::<N>::deserialize(deserializer)
+}
+
+/// Similar to [`deserialize`], except that upper-case hex digits are rejected.
+///
+/// This pairs with [`serialize`] to accept only the canonical lower-case form.
+pub fn deserialize_strict<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::HexStrictArray::<N>::deserialize(deserializer)
+}
+
+/// Similar to [`deserialize`], except that lower-case hex digits are rejected.
+///
+/// This pairs with [`serialize_upper`] to accept only the canonical
+/// upper-case form.
+pub fn deserialize_upper_strict<'de, D, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::HexUpperStrictArray::<N>::deserialize(deserializer)
+}
+
+/// Serialization as `0x`-prefixed hex, as used by Ethereum JSON-RPC.
+///
+/// Deserialization accepts hex with or without the prefix.
+pub mod prefixed {
+    /// Implements serialization for byte arrays to a `0x`-prefixed hex string
+    /// if human-readable, or as bytes if not.
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::Serializer,
+    {
+        crate::human_bytes_type::serialize::<crate::encoding::PrefixedHex, S>(bytes, serializer)
+    }
+
+    /// Deserializes hex strings, with or without a `0x` prefix (if
+    /// human-readable), or byte arrays (if not) to `[u8; N]`.
     ///
-    /// This should work transparently with any `[u8; N].
+    /// In self-describing binary formats such as CBOR, a sequence of exactly
+    /// `N` integers is also accepted, as produced by serde's own
+    /// implementation for `[u8; N]`. Formats that aren't self-describing,
+    /// such as postcard and bincode, only accept the length-prefixed bytes
+    /// written by [`serialize`].
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        crate::PrefixedHexArray::<N>::deserialize(deserializer)
+    }
+}
+
+/// Serialization as hex if human-readable, or with the same representation as
+/// serde's own implementation for `[u8; N]` if not.
+///
+/// In binary formats, serde writes `[u8; N]` as a tuple of `N` integers. For
+/// formats that aren't self-describing, such as postcard and bincode, that's
+/// `N` bare bytes with no length prefix, which [`deserialize`]
+/// can't read. Use this module to switch a field from a plain `[u8; N]` to hex
+/// in human-readable formats without changing its binary format.
+///
+/// ```
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Plain {
+///     key: [u8; 4],
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Hex {
+///     #[serde(with = "serde_human_bytes::hex_array::tuple")]
+///     key: [u8; 4],
+/// }
+///
+/// let bytes = postcard::to_allocvec(&Plain { key: [1, 2, 3, 4] }).unwrap();
+/// let hex: Hex = postcard::from_bytes(&bytes).unwrap();
+/// assert_eq!(hex.key, [1, 2, 3, 4]);
+/// assert_eq!(postcard::to_allocvec(&hex).unwrap(), bytes);
+/// ```
+pub mod tuple {
+    use crate::byte_visitor::ByteArrayVisitor;
+    use serde_core::{Deserializer, Serializer, ser::SerializeTuple};
+
+    /// Implements serialization for byte arrays to a hex string if
+    /// human-readable, or as a tuple of bytes if not.
     pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         if serializer.is_human_readable() {
-            let s = bytes.encode_hex::<alloc::string::String>();
-            serializer.serialize_str(&s)
+            super::serialize(bytes, serializer)
         } else {
-            serializer.serialize_bytes(bytes)
+            let mut tuple = serializer.serialize_tuple(bytes.len())?;
+            for byte in bytes {
+                tuple.serialize_element(byte)?;
+            }
+            tuple.end()
         }
     }
 
-    /// Similar to [`serialize`], except to upper-case.
-    pub fn serialize_upper<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    /// Deserializes hex strings (if human-readable) or tuples of exactly `N`
+    /// bytes (if not) to `[u8; N]`.
+    ///
+    /// Self-describing formats may also provide a byte string of length `N`.
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
     where
-        S: Serializer,
+        D: Deserializer<'de>,
     {
-        if serializer.is_human_readable() {
-            let s = bytes.encode_hex_upper::<alloc::string::String>();
-            serializer.serialize_str(&s)
+        if deserializer.is_human_readable() {
+            super::deserialize(deserializer)
         } else {
-            serializer.serialize_bytes(bytes)
+            deserializer.deserialize_tuple(N, ByteArrayVisitor)
         }
     }
 }
 
-#[cfg(feature = "alloc")]
-pub use alloc_impls::*;
-
-/// Deserializes hex strings (if human-readable) or byte arrays (if not) to `[u8; N]`.
-pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
-where
-    D: Deserializer<'de>,
-{
-    use serde_core::de::Error;
-
-    if deserializer.is_human_readable() {
-        // Bleh, hex::FromHex doesn't have an implementation for const-generic N sadly. Do our own
-        // thing.
-        struct HexVisitor<const N: usize>;
-
-        impl<'de2, const N: usize> Visitor<'de2> for HexVisitor<N> {
-            type Value = [u8; N];
-
-            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, "a hex-encoded string {} bytes long", N)
-            }
-
-            fn visit_str<E>(self, data: &str) -> Result<Self::Value, E>
-            where
-                E: Error,
-            {
-                let mut out = [0u8; N];
-                hex::decode_to_slice(data, &mut out).map_err(Error::custom)?;
-                Ok(out)
-            }
+/// Formats bytes as hex without allocating, by encoding through a small
+/// buffer on the stack.
+pub(crate) struct HexDisplay<'a> {
+    bytes: &'a [u8],
+    upper: bool,
+}
 
-            fn visit_borrowed_str<E>(self, data: &'de2 str) -> Result<Self::Value, E>
-            where
-                E: Error,
-            {
-                let mut out = [0u8; N];
-                hex::decode_to_slice(data, &mut out).map_err(Error::custom)?;
-                Ok(out)
-            }
+impl<'a> HexDisplay<'a> {
+    #[inline]
+    pub(crate) fn lower(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            upper: false,
         }
+    }
 
-        deserializer.deserialize_str(HexVisitor)
-    } else {
-        struct BytesVisitor<const N: usize>;
-
-        impl<'de2, const N: usize> Visitor<'de2> for BytesVisitor<N> {
-            type Value = [u8; N];
-
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "a byte array [u8; {}]", N)
-            }
-
-            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
-            where
-                E: Error,
-            {
-                v.try_into()
-                    .map_err(|_| E::invalid_length(v.len(), &HexExpected::<N>))
-            }
-        }
+    #[inline]
+    pub(crate) fn upper(bytes: &'a [u8]) -> Self {
+        Self { bytes, upper: true }
+    }
+}
 
-        deserializer.deserialize_bytes(BytesVisitor)
+impl fmt::Display for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::human_bytes_type::pad_with(f, |out| self.write_to(out))
     }
 }
 
-struct HexExpected<const N: usize>;
+impl HexDisplay<'_> {
+    fn write_to(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+        const CHUNK_LEN: usize = 32;
 
-impl<const N: usize> Expected for HexExpected<N> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "a byte array [u8; {}]", N)
+        let mut buf = [0u8; CHUNK_LEN * 2];
+        for chunk in self.bytes.chunks(CHUNK_LEN) {
+            let out = &mut buf[..chunk.len() * 2];
+            hex::encode_to_slice(chunk, out).expect("output is exactly twice the input length");
+            if self.upper {
+                out.make_ascii_uppercase();
+            }
+            f.write_str(core::str::from_utf8(out).expect("hex output is ASCII"))?;
+        }
+        Ok(())
     }
 }
@@ -1,12 +1,12 @@
 // Copyright (c) The serde_human_bytes Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-//! The [`HexArray`] newtype wrapper.
+//! The [`HexArray`] type alias, and variants of it.
 
-use crate::hex_array;
-#[cfg(feature = "alloc")]
-use core::fmt;
-use serde_core::Deserializer;
+use crate::{
+    HumanBytes,
+    encoding::{Hex, HexStrict, HexUpper, HexUpperStrict, PrefixedHex},
+};
 
 /// A byte array that serializes as hex in human-readable formats.
 ///
@@ -15,153 +15,94 @@ use serde_core::Deserializer;
 /// 1. Directly as a field type, with serde impls built in.
 /// 2. With `#[serde(with = "HexArray::<N>")]` and
 ///    `#[schemars(with = "HexArray<N>")]` on a `[u8; N]` field.
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
-pub struct HexArray<const N: usize>(pub [u8; N]);
+pub type HexArray<const N: usize> = HumanBytes<Hex, [u8; N]>;
 
-impl<const N: usize> Default for HexArray<N> {
-    fn default() -> Self {
-        Self([0u8; N])
-    }
-}
-
-impl<const N: usize> HexArray<N> {
-    /// Creates a new `HexArray` from a byte array.
-    #[inline]
-    pub const fn new(bytes: [u8; N]) -> Self {
-        Self(bytes)
-    }
-
-    /// Returns the inner byte array.
-    #[inline]
-    pub const fn into_inner(self) -> [u8; N] {
-        self.0
-    }
-
-    /// Serializes a byte array as hex (for `#[serde(with = "HexArray::<N>")]`).
-    #[cfg(feature = "alloc")]
-    pub fn serialize<S>(bytes: &[u8; N], serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde_core::Serializer,
-    {
-        hex_array::serialize(bytes, serializer)
-    }
-
-    /// Deserializes a byte array from hex (for `#[serde(with = "HexArray::<N>")]`).
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<[u8; N], D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        hex_array::deserialize(deserializer)
-    }
-}
-
-#[cfg(feature = "alloc")]
-impl<const N: usize> fmt::Debug for HexArray<N> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "HexArray({})", hex::encode(self.0))
-    }
-}
-
-#[cfg(feature = "alloc")]
-impl<const N: usize> fmt::Display for HexArray<N> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        hex::encode(self.0).fmt(f)
-    }
-}
-
-impl<const N: usize> core::ops::Deref for HexArray<N> {
-    type Target = [u8; N];
-
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-
-impl<const N: usize> core::ops::DerefMut for HexArray<N> {
-    #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
-    }
-}
-
-impl<const N: usize> AsRef<[u8]> for HexArray<N> {
-    #[inline]
-    fn as_ref(&self) -> &[u8] {
-        &self.0
-    }
-}
+/// A byte array that serializes as upper-case hex in human-readable formats,
+/// and accepts either case.
+///
+/// Like [`HexArray`], this can be used directly or with
+/// `#[serde(with = "HexUpperArray::<N>")]`. Its `Display` and `Debug` output
+/// and schema are also in upper case.
+pub type HexUpperArray<const N: usize> = HumanBytes<HexUpper, [u8; N]>;
 
-impl<const N: usize> AsMut<[u8]> for HexArray<N> {
-    #[inline]
-    fn as_mut(&mut self) -> &mut [u8] {
-        &mut self.0
-    }
-}
+/// A byte array that serializes as lower-case hex in human-readable formats,
+/// and rejects upper case.
+///
+/// Use this where only the canonical form should be accepted, such as in data
+/// that is signed.
+pub type HexStrictArray<const N: usize> = HumanBytes<HexStrict, [u8; N]>;
 
-impl<const N: usize> From<[u8; N]> for HexArray<N> {
-    #[inline]
-    fn from(bytes: [u8; N]) -> Self {
-        Self(bytes)
-    }
-}
+/// A byte array that serializes as upper-case hex in human-readable formats,
+/// and rejects lower case.
+pub type HexUpperStrictArray<const N: usize> = HumanBytes<HexUpperStrict, [u8; N]>;
 
-impl<const N: usize> From<HexArray<N>> for [u8; N] {
-    #[inline]
-    fn from(hex_array: HexArray<N>) -> Self {
-        hex_array.0
-    }
-}
+/// A byte array that serializes as `0x`-prefixed hex in human-readable
+/// formats, and accepts hex with or without the prefix.
+///
+/// Like [`HexArray`], this can be used directly or with
+/// `#[serde(with = "PrefixedHexArray::<N>")]`.
+pub type PrefixedHexArray<const N: usize> = HumanBytes<PrefixedHex, [u8; N]>;
 
-#[cfg(feature = "alloc")]
-impl<const N: usize> serde_core::Serialize for HexArray<N> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde_core::Serializer,
-    {
-        hex_array::serialize(&self.0, serializer)
+impl<const N: usize> HexArray<N> {
+    /// Creates a new `HexArray` from a string of exactly `2 * N` hex digits,
+    /// in either case.
+    ///
+    /// This is meant for constants, where invalid input becomes a
+    /// compile-time error. The [`hex_array!`](crate::hex_array!) macro also
+    /// infers `N` from the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` is not exactly `2 * N` hex digits.
+    pub const fn from_hex_const(s: &str) -> Self {
+        let s = s.as_bytes();
+        if s.len() != N * 2 {
+            panic!("hex string has the wrong length");
+        }
+        let mut out = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            out[i] = (hex_digit(s[2 * i]) << 4) | hex_digit(s[2 * i + 1]);
+            i += 1;
+        }
+        Self::new(out)
     }
 }
 
-impl<'de, const N: usize> serde_core::Deserialize<'de> for HexArray<N> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        hex_array::deserialize(deserializer).map(Self)
+/// Decodes a single hex digit, for use in const contexts.
+const fn hex_digit(c: u8) -> u8 {
+    match c {
+        b'0'..=b'9' => c - b'0',
+        b'a'..=b'f' => c - b'a' + 10,
+        b'A'..=b'F' => c - b'A' + 10,
+        _ => panic!("invalid hex digit"),
     }
 }
 
-#[cfg(feature = "schemars08")]
-mod schemars_impls {
-    use super::HexArray;
-    use alloc::{boxed::Box, format, string::String};
-    use schemars08::{
-        JsonSchema,
-        r#gen::SchemaGenerator,
-        schema::{InstanceType, Schema, SchemaObject, StringValidation},
-    };
-
-    impl<const N: usize> JsonSchema for HexArray<N> {
-        fn schema_name() -> String {
-            format!("HexArray_{N}")
-        }
-
-        fn is_referenceable() -> bool {
-            false
-        }
-
-        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
-            let hex_len = N * 2;
-            Schema::Object(SchemaObject {
-                instance_type: Some(InstanceType::String.into()),
-                string: Some(Box::new(StringValidation {
-                    min_length: Some(hex_len as u32),
-                    max_length: Some(hex_len as u32),
-                    pattern: Some(format!("^[0-9a-fA-F]{{{hex_len}}}$")),
-                })),
-                ..Default::default()
-            })
+/// Creates a [`HexArray`] from a hex string literal, inferring its length.
+///
+/// The string is always decoded at compile time, so invalid input is a
+/// compile-time error wherever the macro is used. See
+/// [`HexArray::from_hex_const`] for details.
+///
+/// ```
+/// use serde_human_bytes::{HexArray, hex_array};
+///
+/// const KEY: HexArray<4> = hex_array!("deadbeef");
+/// let key = hex_array!("DEADBEEF");
+/// assert_eq!(key, KEY);
+/// ```
+///
+/// ```compile_fail
+/// // Not a hex digit, even outside a constant.
+/// let key = serde_human_bytes::hex_array!("zz");
+/// ```
+#[macro_export]
+macro_rules! hex_array {
+    ($s:expr) => {
+        const {
+            const S: &str = $s;
+            $crate::HexArray::<{ S.len() / 2 }>::from_hex_const(S)
         }
-    }
+    };
 }
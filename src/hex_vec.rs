@@ -0,0 +1,58 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte vector as hex if human-readable, or as bytes if not.
+
+use alloc::vec::Vec;
+use serde_core::{Deserializer, Serializer};
+
+/// Implements serialization for byte vectors to a hex string if
+/// human-readable, or as bytes if not.
+pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::human_bytes_type::serialize::<crate::encoding::Hex, S>(bytes, serializer)
+}
+
+/// Similar to [`serialize`], except to upper-case.
+pub fn serialize_upper<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::human_bytes_type::serialize::<crate::encoding::HexUpper, S>(bytes, serializer)
+}
+
+/// Deserializes hex strings (if human-readable) or byte arrays (if not) to
+/// `Vec<u8>`.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::HexVec::deserialize(deserializer)
+}
+
+/// Serialization as `0x`-prefixed hex, as used by Ethereum JSON-RPC.
+///
+/// Deserialization accepts hex with or without the prefix.
+pub mod prefixed {
+    use alloc::vec::Vec;
+
+    /// Implements serialization for byte vectors to a `0x`-prefixed hex
+    /// string if human-readable, or as bytes if not.
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_core::Serializer,
+    {
+        crate::human_bytes_type::serialize::<crate::encoding::PrefixedHex, S>(bytes, serializer)
+    }
+
+    /// Deserializes hex strings, with or without a `0x` prefix (if
+    /// human-readable), or byte arrays (if not) to `Vec<u8>`.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: serde_core::Deserializer<'de>,
+    {
+        crate::PrefixedHexVec::deserialize(deserializer)
+    }
+}
@@ -0,0 +1,26 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`HexVec`] and [`PrefixedHexVec`] type aliases.
+
+use crate::{
+    HumanBytes,
+    encoding::{Hex, PrefixedHex},
+};
+use alloc::vec::Vec;
+
+/// A byte vector that serializes as hex in human-readable formats.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "HexVec")]` and `#[schemars(with = "HexVec")]` on a
+///    `Vec<u8>` field.
+pub type HexVec = HumanBytes<Hex, Vec<u8>>;
+
+/// A byte vector that serializes as `0x`-prefixed hex in human-readable
+/// formats, and accepts hex with or without the prefix.
+///
+/// Like [`HexVec`], this can be used directly or with
+/// `#[serde(with = "PrefixedHexVec")]`.
+pub type PrefixedHexVec = HumanBytes<PrefixedHex, Vec<u8>>;
@@ -0,0 +1,740 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`HumanBytes`] newtype wrapper, generic over an encoding and a byte
+//! container.
+
+use crate::{
+    DecodeError,
+    byte_visitor::{ByteArrayExpected, ByteArrayVisitor},
+    encoding::Encoding,
+    hex_array::HexDisplay,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    str::FromStr,
+};
+use serde_core::{
+    Deserializer, Serializer,
+    de::{Expected, Visitor},
+    ser::Error as _,
+};
+
+/// Bytes that serialize as text in encoding `E` in human-readable formats,
+/// and as bytes otherwise.
+///
+/// `C` is the container for the bytes: either `[u8; N]` or `Vec<u8>`. The
+/// other types in this crate, such as [`HexArray`](crate::HexArray) and
+/// [`Base64Vec`](crate::Base64Vec), are aliases for this type with one of the
+/// encodings in [`encoding`](crate::encoding). As aliases, they're built
+/// with [`new`](Self::new) or `From` rather than as tuple structs: write
+/// `HexArray::new(bytes)`, not `HexArray(bytes)`.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "HumanBytes::<E, C>")]` and
+///    `#[schemars(with = "HumanBytes<E, C>")]` on a field of type `C`.
+pub struct HumanBytes<E, C>(pub C, PhantomData<fn() -> E>);
+
+/// A container for bytes in [`HumanBytes`]: either `[u8; N]` or `Vec<u8>`.
+///
+/// This trait is sealed, and can't be implemented outside this crate.
+pub trait ByteContainer: AsRef<[u8]> + AsMut<[u8]> + Sized + private::Sealed {
+    /// The name of the container, used as a suffix in `Debug` output and
+    /// schema names.
+    const NAME: &'static str;
+
+    /// The number of bytes in the container, if fixed.
+    const LEN: Option<usize>;
+
+    /// Decodes `data` with `E` into this container.
+    fn decode<E: Encoding>(data: &str) -> Result<Self, DecodeError>;
+
+    /// Deserializes this container from bytes, in non-human-readable formats.
+    fn deserialize_bytes<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+
+    /// Describes this container in length errors.
+    fn fmt_expected(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+mod private {
+    pub trait Sealed {}
+
+    impl<const N: usize> Sealed for [u8; N] {}
+
+    #[cfg(feature = "alloc")]
+    impl Sealed for alloc::vec::Vec<u8> {}
+}
+
+impl<const N: usize> ByteContainer for [u8; N] {
+    const NAME: &'static str = "Array";
+    const LEN: Option<usize> = Some(N);
+
+    fn decode<E: Encoding>(data: &str) -> Result<Self, DecodeError> {
+        let mut out = [0u8; N];
+        E::decode_exact(data, &mut out)?;
+        Ok(out)
+    }
+
+    fn deserialize_bytes<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(ByteArrayVisitor)
+    }
+
+    fn fmt_expected(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ByteArrayExpected::<N>.fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ByteContainer for Vec<u8> {
+    const NAME: &'static str = "Vec";
+    const LEN: Option<usize> = None;
+
+    fn decode<E: Encoding>(data: &str) -> Result<Self, DecodeError> {
+        E::decode_vec(data)
+    }
+
+    fn deserialize_bytes<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_byte_buf(crate::byte_visitor::ByteVecVisitor)
+    }
+
+    fn fmt_expected(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte array")
+    }
+}
+
+impl<E, C> HumanBytes<E, C> {
+    /// Creates a new `HumanBytes` from a byte container.
+    #[inline]
+    pub const fn new(bytes: C) -> Self {
+        Self(bytes, PhantomData)
+    }
+}
+
+impl<E, const N: usize> HumanBytes<E, [u8; N]> {
+    /// Returns the inner byte array.
+    #[inline]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> HumanBytes<E, Vec<u8>> {
+    /// Returns the inner byte vector.
+    #[inline]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+impl<E, C> HumanBytes<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    const VALID: () = assert!(
+        match C::LEN {
+            Some(len) => len % E::LEN_MULTIPLE == 0,
+            None => true,
+        },
+        "the array length must be a multiple of the encoding's `LEN_MULTIPLE`"
+    );
+
+    /// Serializes bytes as text in encoding `E` (for
+    /// `#[serde(with = "HumanBytes::<E, C>")]`).
+    pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let () = Self::VALID;
+        serialize::<E, S>(bytes, serializer)
+    }
+
+    /// Deserializes bytes from text in encoding `E` (for
+    /// `#[serde(with = "HumanBytes::<E, C>")]`).
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let () = Self::VALID;
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(EncodedVisitor::<E, C>(PhantomData))
+        } else {
+            C::deserialize_bytes(deserializer)
+        }
+    }
+}
+
+/// Serializes bytes as text in encoding `E` if human-readable, or as bytes if
+/// not.
+pub(crate) fn serialize<E, S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    E: Encoding,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        E::check_len(bytes.len()).map_err(S::Error::custom)?;
+        serializer.collect_str(&EncodeDisplay::<E>(bytes, PhantomData))
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+/// Formats bytes in encoding `E`.
+struct EncodeDisplay<'a, E>(&'a [u8], PhantomData<fn() -> E>);
+
+impl<E: Encoding> fmt::Display for EncodeDisplay<'_, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        E::encode(self.0, f)
+    }
+}
+
+/// Visits a string in encoding `E` in human-readable formats.
+struct EncodedVisitor<E, C>(PhantomData<fn() -> (E, C)>);
+
+impl<'de, E, C> Visitor<'de> for EncodedVisitor<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    type Value = C;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(E::EXPECTING)?;
+        if let Some(len) = C::LEN {
+            write!(f, " {} bytes long", len)?;
+        }
+        Ok(())
+    }
+
+    fn visit_str<Err>(self, data: &str) -> Result<Self::Value, Err>
+    where
+        Err: serde_core::de::Error,
+    {
+        C::decode::<E>(data)
+            .map_err(|error| error.into_de_error(&ContainerExpected::<C>(PhantomData)))
+    }
+
+    fn visit_borrowed_str<Err>(self, data: &'de str) -> Result<Self::Value, Err>
+    where
+        Err: serde_core::de::Error,
+    {
+        self.visit_str(data)
+    }
+}
+
+struct ContainerExpected<C>(PhantomData<fn() -> C>);
+
+impl<C: ByteContainer> Expected for ContainerExpected<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        C::fmt_expected(f)
+    }
+}
+
+impl<E, C: Clone> Clone for HumanBytes<E, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.0.clone())
+    }
+}
+
+impl<E, C: Copy> Copy for HumanBytes<E, C> {}
+
+impl<E, C: PartialEq> PartialEq for HumanBytes<E, C> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E, C: Eq> Eq for HumanBytes<E, C> {}
+
+impl<E, C: PartialOrd> PartialOrd for HumanBytes<E, C> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<E, C: Ord> Ord for HumanBytes<E, C> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<E, C: Hash> Hash for HumanBytes<E, C> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<E, const N: usize> Default for HumanBytes<E, [u8; N]> {
+    #[inline]
+    fn default() -> Self {
+        Self::new([0u8; N])
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> Default for HumanBytes<E, Vec<u8>> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<E, C> fmt::Debug for HumanBytes<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let () = Self::VALID;
+        let bytes = self.0.as_ref();
+        write!(f, "{}{}(", E::NAME, C::NAME)?;
+        encode_or_hex::<E>(bytes, f)?;
+        f.write_str(")")
+    }
+}
+
+impl<E, C> fmt::Display for HumanBytes<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let () = Self::VALID;
+        pad_with(f, |out| encode_or_hex::<E>(self.0.as_ref(), out))
+    }
+}
+
+/// Writes `bytes` in encoding `E`, or as hex if `E` can't encode that many
+/// bytes.
+///
+/// `Debug` and `Display` shouldn't fail unless the writer does, so lengths
+/// that [`Encoding::check_len`] rejects are shown as
+/// `<invalid length N: hex>` rather than returning [`fmt::Error`].
+fn encode_or_hex<E: Encoding>(bytes: &[u8], out: &mut (impl fmt::Write + ?Sized)) -> fmt::Result {
+    match E::check_len(bytes.len()) {
+        Ok(()) => E::encode(bytes, out),
+        Err(_) => write!(
+            out,
+            "<invalid length {}: {}>",
+            bytes.len(),
+            HexDisplay::lower(bytes)
+        ),
+    }
+}
+
+/// Writes the output of `write` to `f`, honoring its width, fill, alignment
+/// and precision the way [`fmt::Formatter::pad`] does for a `str`.
+///
+/// To do so without allocating, `write` is called twice when padding or
+/// truncating: once to count the characters, and once to write them.
+pub(crate) fn pad_with<F>(f: &mut fmt::Formatter<'_>, write: F) -> fmt::Result
+where
+    F: Fn(&mut dyn fmt::Write) -> fmt::Result,
+{
+    if f.width().is_none() && f.precision().is_none() {
+        return write(f);
+    }
+
+    let mut counter = CharCounter(0);
+    write(&mut counter)?;
+    let len = f
+        .precision()
+        .map_or(counter.0, |precision| precision.min(counter.0));
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        fmt::Write::write_char(f, fill)?;
+    }
+    write(&mut Truncate {
+        out: f,
+        remaining: len,
+    })?;
+    for _ in 0..after {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+/// Counts the characters written to it.
+struct CharCounter(usize);
+
+impl fmt::Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes at most `remaining` more characters to `out`, discarding the rest.
+struct Truncate<'a, W: ?Sized> {
+    out: &'a mut W,
+    remaining: usize,
+}
+
+impl<W: fmt::Write + ?Sized> fmt::Write for Truncate<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = s
+            .char_indices()
+            .nth(self.remaining)
+            .map_or(s.len(), |(index, _)| index);
+        self.remaining -= s[..end].chars().count();
+        self.out.write_str(&s[..end])
+    }
+}
+
+impl<E, C> core::ops::Deref for HumanBytes<E, C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E, C> core::ops::DerefMut for HumanBytes<E, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<E, C: ByteContainer> AsRef<[u8]> for HumanBytes<E, C> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<E, C: ByteContainer> AsMut<[u8]> for HumanBytes<E, C> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+// These are consistent with the `Eq`, `Ord` and `Hash` impls above, since
+// arrays and vectors compare and hash the same way as slices.
+impl<E, const N: usize> Borrow<[u8; N]> for HumanBytes<E, [u8; N]> {
+    #[inline]
+    fn borrow(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<E, C: ByteContainer> Borrow<[u8]> for HumanBytes<E, C> {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<E, C: ByteContainer> From<C> for HumanBytes<E, C> {
+    #[inline]
+    fn from(bytes: C) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<E, const N: usize> From<HumanBytes<E, [u8; N]>> for [u8; N] {
+    #[inline]
+    fn from(value: HumanBytes<E, [u8; N]>) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> From<HumanBytes<E, Vec<u8>>> for Vec<u8> {
+    #[inline]
+    fn from(value: HumanBytes<E, Vec<u8>>) -> Self {
+        value.0
+    }
+}
+
+impl<E, const N: usize> TryFrom<&[u8]> for HumanBytes<E, [u8; N]> {
+    type Error = DecodeError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        bytes
+            .try_into()
+            .map(Self::new)
+            .map_err(|_| DecodeError::InvalidLength {
+                expected: N,
+                actual: bytes.len(),
+            })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> From<&[u8]> for HumanBytes<E, Vec<u8>> {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        Self::new(bytes.to_vec())
+    }
+}
+
+/// Parses text in encoding `E`. For arrays, the text must decode to exactly
+/// `N` bytes.
+impl<E, C> FromStr for HumanBytes<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let () = Self::VALID;
+        C::decode::<E>(s).map(Self::new)
+    }
+}
+
+impl<E, C> TryFrom<&str> for HumanBytes<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    type Error = DecodeError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<E, C> serde_core::Serialize for HumanBytes<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Self::serialize(self.0.as_ref(), serializer)
+    }
+}
+
+impl<'de, E, C> serde_core::Deserialize<'de> for HumanBytes<E, C>
+where
+    E: Encoding,
+    C: ByteContainer,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::deserialize(deserializer).map(Self::new)
+    }
+}
+
+/// Returns the string schema for `HumanBytes<E, C>`.
+#[cfg(any(feature = "schemars08", feature = "schemars1", feature = "utoipa"))]
+fn string_schema<E: Encoding, C: ByteContainer>() -> crate::encoding::StringSchema {
+    let () = HumanBytes::<E, C>::VALID;
+    E::schema(C::LEN)
+}
+
+/// Returns the schema name for `HumanBytes<E, C>`, such as `HexArray_16`.
+#[cfg(any(feature = "schemars08", feature = "schemars1", feature = "utoipa"))]
+fn schema_name<E: Encoding, C: ByteContainer>() -> alloc::string::String {
+    let suffix = E::schema_name_suffix();
+    match C::LEN {
+        Some(len) => alloc::format!("{}{}_{}{}", E::NAME, C::NAME, len, suffix),
+        None => alloc::format!("{}{}{}", E::NAME, C::NAME, suffix),
+    }
+}
+
+#[cfg(feature = "subtle")]
+mod subtle_impls {
+    use super::{ByteContainer, HumanBytes};
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+    impl<E, C: ByteContainer> HumanBytes<E, C> {
+        /// Compares two byte containers in constant time.
+        ///
+        /// This is the same as [`ConstantTimeEq::ct_eq`], without needing the
+        /// trait in scope. Use `bool::from` to convert the result. Vectors of
+        /// different lengths compare unequal immediately.
+        #[inline]
+        pub fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.as_ref().ct_eq(other.0.as_ref())
+        }
+    }
+
+    impl<E, C: ByteContainer> ConstantTimeEq for HumanBytes<E, C> {
+        #[inline]
+        fn ct_eq(&self, other: &Self) -> Choice {
+            HumanBytes::ct_eq(self, other)
+        }
+    }
+
+    impl<E, const N: usize> ConditionallySelectable for HumanBytes<E, [u8; N]> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            let mut out = *a;
+            for (out, b) in out.0.iter_mut().zip(&b.0) {
+                out.conditional_assign(b, choice);
+            }
+            out
+        }
+    }
+}
+
+#[cfg(feature = "schemars08")]
+mod schemars_impls {
+    use super::{ByteContainer, HumanBytes, schema_name, string_schema};
+    use crate::encoding::Encoding;
+    use alloc::{boxed::Box, string::String};
+    use schemars08::{
+        JsonSchema,
+        r#gen::SchemaGenerator,
+        schema::{InstanceType, Schema, SchemaObject, StringValidation},
+    };
+
+    impl<E, C> JsonSchema for HumanBytes<E, C>
+    where
+        E: Encoding,
+        C: ByteContainer,
+    {
+        fn schema_name() -> String {
+            schema_name::<E, C>()
+        }
+
+        fn is_referenceable() -> bool {
+            false
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            let schema = string_schema::<E, C>();
+            Schema::Object(SchemaObject {
+                instance_type: Some(InstanceType::String.into()),
+                format: schema.format.map(Into::into),
+                string: Some(Box::new(StringValidation {
+                    min_length: schema.min_length.map(|len| len as u32),
+                    max_length: schema.max_length.map(|len| len as u32),
+                    pattern: schema.pattern,
+                })),
+                extensions: schema
+                    .content_encoding
+                    .map(|encoding| ("contentEncoding".into(), encoding.into()))
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            })
+        }
+    }
+}
+
+#[cfg(feature = "schemars1")]
+mod schemars1_impls {
+    use super::{ByteContainer, HumanBytes, schema_name, string_schema};
+    use crate::encoding::Encoding;
+    use alloc::borrow::Cow;
+    use schemars1::{JsonSchema, Schema, SchemaGenerator, json_schema};
+
+    impl<E, C> JsonSchema for HumanBytes<E, C>
+    where
+        E: Encoding,
+        C: ByteContainer,
+    {
+        fn inline_schema() -> bool {
+            true
+        }
+
+        fn schema_name() -> Cow<'static, str> {
+            schema_name::<E, C>().into()
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            let schema = string_schema::<E, C>();
+            let mut out = json_schema!({ "type": "string" });
+            if let Some(format) = schema.format {
+                out.insert("format".into(), format.into());
+            }
+            if let Some(encoding) = schema.content_encoding {
+                out.insert("contentEncoding".into(), encoding.into());
+            }
+            if let Some(len) = schema.min_length {
+                out.insert("minLength".into(), len.into());
+            }
+            if let Some(len) = schema.max_length {
+                out.insert("maxLength".into(), len.into());
+            }
+            if let Some(pattern) = schema.pattern {
+                out.insert("pattern".into(), pattern.into());
+            }
+            out
+        }
+    }
+}
+
+#[cfg(feature = "utoipa")]
+mod utoipa_impls {
+    use super::{ByteContainer, HumanBytes, schema_name, string_schema};
+    use crate::encoding::Encoding;
+    use alloc::borrow::Cow;
+    use utoipa::{
+        PartialSchema, ToSchema,
+        openapi::{KnownFormat, ObjectBuilder, RefOr, Schema, SchemaFormat, Type},
+    };
+
+    impl<E, C> PartialSchema for HumanBytes<E, C>
+    where
+        E: Encoding,
+        C: ByteContainer,
+    {
+        fn schema() -> RefOr<Schema> {
+            let schema = string_schema::<E, C>();
+            let format = schema.format.map(|format| match format {
+                "byte" => SchemaFormat::KnownFormat(KnownFormat::Byte),
+                format => SchemaFormat::Custom(format.into()),
+            });
+            let mut builder = ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(format)
+                .min_length(schema.min_length)
+                .max_length(schema.max_length)
+                .pattern(schema.pattern);
+            if let Some(encoding) = schema.content_encoding {
+                builder = builder.content_encoding(encoding);
+            }
+            builder.into()
+        }
+    }
+
+    impl<E, C> ToSchema for HumanBytes<E, C>
+    where
+        E: Encoding,
+        C: ByteContainer,
+    {
+        fn name() -> Cow<'static, str> {
+            schema_name::<E, C>().into()
+        }
+    }
+}
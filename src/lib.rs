@@ -13,13 +13,102 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod base32_array;
+mod base32_array_type;
+#[cfg(feature = "alloc")]
+pub mod base32_vec;
+#[cfg(feature = "alloc")]
+mod base32_vec_type;
+#[cfg(feature = "base58")]
+pub mod base58_array;
+#[cfg(feature = "base58")]
+mod base58_array_type;
+#[cfg(feature = "base58")]
+pub mod base58_vec;
+#[cfg(feature = "base58")]
+mod base58_vec_type;
+#[cfg(feature = "alloc")]
+pub mod base64_array;
+#[cfg(feature = "alloc")]
+mod base64_array_type;
+#[cfg(feature = "alloc")]
+pub mod base64_cow;
+#[cfg(feature = "alloc")]
+mod base64_cow_type;
 #[cfg(feature = "alloc")]
 pub mod base64_vec;
 #[cfg(feature = "alloc")]
 mod base64_vec_type;
+pub mod base85_array;
+mod base85_array_type;
+#[cfg(feature = "alloc")]
+pub mod base85_vec;
+#[cfg(feature = "alloc")]
+mod base85_vec_type;
+#[cfg(feature = "bech32")]
+pub mod bech32_array;
+#[cfg(feature = "bech32")]
+mod bech32_array_type;
+#[cfg(all(feature = "bech32", feature = "alloc"))]
+pub mod bech32_vec;
+#[cfg(all(feature = "bech32", feature = "alloc"))]
+mod bech32_vec_type;
+#[cfg(feature = "alloc")]
+mod bounded_base64_vec_type;
+mod byte_visitor;
+pub mod encoding;
+mod error;
 pub mod hex_array;
 mod hex_array_type;
+#[cfg(feature = "alloc")]
+pub mod hex_vec;
+#[cfg(feature = "alloc")]
+mod hex_vec_type;
+mod human_bytes_type;
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+mod secret_base64_vec_type;
+#[cfg(feature = "zeroize")]
+mod secret_hex_array_type;
+pub mod separated_hex_array;
+mod separated_hex_array_type;
 
+pub use base32_array_type::{
+    Base32Array, Base32CrockfordArray, Base32HexArray, Base32HexNoPadArray, Base32NoPadArray,
+};
+#[cfg(feature = "alloc")]
+pub use base32_vec_type::{
+    Base32CrockfordVec, Base32HexNoPadVec, Base32HexVec, Base32NoPadVec, Base32Vec,
+};
+#[cfg(feature = "base58")]
+pub use base58_array_type::{Base58Array, Base58CheckArray};
+#[cfg(feature = "base58")]
+pub use base58_vec_type::{Base58CheckVec, Base58Vec};
+#[cfg(feature = "alloc")]
+pub use base64_array_type::Base64Array;
+#[cfg(feature = "alloc")]
+pub use base64_cow_type::Base64Cow;
+#[cfg(feature = "alloc")]
+pub use base64_vec_type::{
+    Base64LenientVec, Base64NoPadVec, Base64UrlNoPadVec, Base64UrlVec, Base64Vec,
+};
+pub use base85_array_type::{Ascii85Array, Z85Array};
+#[cfg(feature = "alloc")]
+pub use base85_vec_type::{Ascii85Vec, Z85Vec};
+#[cfg(feature = "bech32")]
+pub use bech32_array_type::{Bech32Array, Bech32mArray};
+#[cfg(all(feature = "bech32", feature = "alloc"))]
+pub use bech32_vec_type::{Bech32Vec, Bech32mVec};
+#[cfg(feature = "alloc")]
+pub use bounded_base64_vec_type::BoundedBase64Vec;
+pub use error::{DecodeError, FoundPrefix};
+pub use hex_array_type::{
+    HexArray, HexStrictArray, HexUpperArray, HexUpperStrictArray, PrefixedHexArray,
+};
 #[cfg(feature = "alloc")]
-pub use base64_vec_type::Base64Vec;
-pub use hex_array_type::HexArray;
+pub use hex_vec_type::{HexVec, PrefixedHexVec};
+pub use human_bytes_type::{ByteContainer, HumanBytes};
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+pub use secret_base64_vec_type::SecretBase64Vec;
+#[cfg(feature = "zeroize")]
+pub use secret_hex_array_type::SecretHexArray;
+pub use separated_hex_array_type::SeparatedHexArray;
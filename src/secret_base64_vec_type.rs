@@ -0,0 +1,140 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`SecretBase64Vec`] newtype wrapper.
+
+use crate::{
+    DecodeError, base64_vec,
+    encoding::{Base64, Encoding},
+};
+use alloc::vec::Vec;
+use core::{fmt, str::FromStr};
+use serde_core::{Deserializer, Serializer};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A secret byte vector that serializes as base64 in human-readable formats.
+///
+/// This serializes and deserializes exactly like
+/// [`Base64Vec`](crate::Base64Vec), but is meant for key material and other
+/// secrets:
+///
+/// * `Debug` and `Display` print `[REDACTED]` rather than the contents.
+/// * The contents are zeroized when the value is dropped.
+/// * Equality comparisons run in constant time, although vectors of
+///   different lengths compare unequal immediately.
+///
+/// Use [`expose_secret`](Self::expose_secret) to access the bytes.
+///
+/// Zeroizing on drop only clears this value. Copies made elsewhere, such as
+/// the serialized base64 string or a deserializer's input buffer, are not
+/// cleared.
+#[derive(Clone, Default)]
+pub struct SecretBase64Vec(Vec<u8>);
+
+impl SecretBase64Vec {
+    /// Creates a new `SecretBase64Vec` from a byte vector.
+    #[inline]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns a reference to the secret bytes.
+    #[inline]
+    pub fn expose_secret(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the secret bytes.
+    ///
+    /// This is a slice rather than the `Vec` itself, since growing the
+    /// vector could reallocate and leave a copy of the secret behind.
+    #[inline]
+    pub fn expose_secret_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for SecretBase64Vec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretBase64Vec([REDACTED])")
+    }
+}
+
+impl fmt::Display for SecretBase64Vec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl ConstantTimeEq for SecretBase64Vec {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+impl PartialEq for SecretBase64Vec {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SecretBase64Vec {}
+
+impl Zeroize for SecretBase64Vec {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for SecretBase64Vec {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecretBase64Vec {}
+
+impl From<Vec<u8>> for SecretBase64Vec {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Parses standard, padded base64.
+impl FromStr for SecretBase64Vec {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Base64::decode_vec(s).map(Self)
+    }
+}
+
+impl TryFrom<&str> for SecretBase64Vec {
+    type Error = DecodeError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl serde_core::Serialize for SecretBase64Vec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        base64_vec::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> serde_core::Deserialize<'de> for SecretBase64Vec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        base64_vec::deserialize(deserializer).map(Self)
+    }
+}
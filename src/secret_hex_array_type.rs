@@ -0,0 +1,137 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`SecretHexArray`] newtype wrapper.
+
+use crate::{
+    DecodeError,
+    encoding::{Encoding, Hex},
+    hex_array,
+};
+use core::{fmt, str::FromStr};
+use serde_core::{Deserializer, Serializer};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A secret byte array that serializes as hex in human-readable formats.
+///
+/// This serializes and deserializes exactly like [`HexArray`](crate::HexArray),
+/// but is meant for key material and other secrets:
+///
+/// * `Debug` and `Display` print `[REDACTED]` rather than the contents.
+/// * The contents are zeroized when the value is dropped.
+/// * Equality comparisons run in constant time.
+///
+/// Use [`expose_secret`](Self::expose_secret) to access the bytes.
+///
+/// Zeroizing on drop only clears this value. Copies made elsewhere, such as
+/// the serialized hex string or a deserializer's input buffer, are not
+/// cleared.
+#[derive(Clone)]
+pub struct SecretHexArray<const N: usize>([u8; N]);
+
+impl<const N: usize> SecretHexArray<N> {
+    /// Creates a new `SecretHexArray` from a byte array.
+    #[inline]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns a reference to the secret bytes.
+    #[inline]
+    pub fn expose_secret(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the secret bytes.
+    #[inline]
+    pub fn expose_secret_mut(&mut self) -> &mut [u8; N] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> fmt::Debug for SecretHexArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretHexArray([REDACTED])")
+    }
+}
+
+impl<const N: usize> fmt::Display for SecretHexArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for SecretHexArray<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+impl<const N: usize> PartialEq for SecretHexArray<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<const N: usize> Eq for SecretHexArray<N> {}
+
+impl<const N: usize> Zeroize for SecretHexArray<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const N: usize> Drop for SecretHexArray<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for SecretHexArray<N> {}
+
+impl<const N: usize> From<[u8; N]> for SecretHexArray<N> {
+    #[inline]
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+/// Parses a hex string of exactly `2 * N` digits, in either case.
+impl<const N: usize> FromStr for SecretHexArray<N> {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut out = Self([0u8; N]);
+        Hex::decode_exact(s, &mut out.0)?;
+        Ok(out)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for SecretHexArray<N> {
+    type Error = DecodeError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const N: usize> serde_core::Serialize for SecretHexArray<N> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        hex_array::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, const N: usize> serde_core::Deserialize<'de> for SecretHexArray<N> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        hex_array::deserialize(deserializer).map(Self)
+    }
+}
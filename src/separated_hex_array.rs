@@ -0,0 +1,54 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serialize a byte array as hex with separators if human-readable, or as
+//! bytes if not.
+//!
+//! The functions at the top level of this module put a colon between every
+//! byte, in lower case, such as `aa:bb:cc:dd:ee:ff`. The [`dash`] submodule
+//! uses a hyphen instead, such as `aa-bb-cc-dd-ee-ff`. For other separators,
+//! group sizes or upper case, use
+//! [`SeparatedHexArray`](crate::SeparatedHexArray) with explicit parameters.
+//! See [`SeparatedHex`](crate::encoding::SeparatedHex) for details.
+
+/// Defines `serialize` and `deserialize` functions for byte arrays in
+/// `$encoding`.
+macro_rules! separated_hex_array_functions {
+    ($encoding:ty, $description:literal) => {
+        #[doc = concat!("Implements serialization for byte arrays to ", $description, " if")]
+        #[doc = "human-readable, or as bytes if not."]
+        pub fn serialize<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde_core::Serializer,
+        {
+            crate::human_bytes_type::serialize::<$encoding, S>(bytes, serializer)
+        }
+
+        #[doc = concat!("Deserializes ", $description, "s (if human-readable) or byte arrays")]
+        #[doc = "(if not) to `[u8; N]`."]
+        #[doc = ""]
+        #[doc = "In self-describing binary formats such as CBOR, a sequence of exactly `N`"]
+        #[doc = "integers is also accepted, as produced by serde's own implementation for"]
+        #[doc = "`[u8; N]`. Formats that aren't self-describing, such as postcard and"]
+        #[doc = "bincode, only accept the length-prefixed bytes written by [`serialize`]."]
+        pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+        where
+            D: serde_core::Deserializer<'de>,
+        {
+            crate::HumanBytes::<$encoding, [u8; N]>::deserialize(deserializer)
+        }
+    };
+}
+
+separated_hex_array_functions!(
+    crate::encoding::SeparatedHex<':'>,
+    "a colon-separated hex string"
+);
+
+/// Serialization with a hyphen between every byte.
+pub mod dash {
+    separated_hex_array_functions!(
+        crate::encoding::SeparatedHex<'-'>,
+        "a hyphen-separated hex string"
+    );
+}
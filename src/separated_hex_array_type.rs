@@ -0,0 +1,26 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The [`SeparatedHexArray`] type alias.
+
+use crate::{HumanBytes, encoding::SeparatedHex};
+
+/// A byte array that serializes as hex with separators in human-readable
+/// formats, such as `aa:bb:cc:dd:ee:ff` for a MAC address.
+///
+/// `SEP` is placed between every `GROUP` bytes, and `UPPER` selects upper-case
+/// hex. See [`SeparatedHex`] for details.
+///
+/// This type can be used in two ways:
+///
+/// 1. Directly as a field type, with serde impls built in.
+/// 2. With `#[serde(with = "SeparatedHexArray::<N>")]` and
+///    `#[schemars(with = "SeparatedHexArray<N>")]` on a `[u8; N]` field, or
+///    with the other parameters given explicitly, such as
+///    `SeparatedHexArray::<8, '-', 2, true>`.
+pub type SeparatedHexArray<
+    const N: usize,
+    const SEP: char = ':',
+    const GROUP: usize = 1,
+    const UPPER: bool = false,
+> = HumanBytes<SeparatedHex<SEP, GROUP, UPPER>, [u8; N]>;
@@ -0,0 +1,223 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{
+    Base32Array, Base32CrockfordArray, Base32CrockfordVec, Base32HexNoPadArray, Base32HexNoPadVec,
+    Base32HexVec, Base32NoPadArray, Base32NoPadVec, Base32Vec, DecodeError,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    #[serde(with = "serde_human_bytes::base32_array")]
+    array: [u8; 6],
+    #[serde(with = "serde_human_bytes::base32_vec::no_pad")]
+    vec: Vec<u8>,
+    #[serde(with = "serde_human_bytes::base32_array::crockford")]
+    id: [u8; 5],
+}
+
+static AS_JSON: &str = r#"{"array":"MZXW6YTBOI======","vec":"MZXW6","id":"CSQPYRK1"}"#;
+
+// CBOR: map with three keys, each with a byte string value.
+static AS_CBOR: [u8; 31] =
+    hex!("a3656172726179 46666f6f626172 63766563 43666f6f 626964 45666f6f6261");
+
+fn fixture() -> MyStruct {
+    MyStruct {
+        array: *b"foobar",
+        vec: b"foo".to_vec(),
+        id: *b"fooba",
+    }
+}
+
+#[test]
+fn base32_serialize() {
+    let fixture = fixture();
+
+    assert_eq!(
+        serde_json::to_string(&fixture).expect("serializing as JSON succeeded"),
+        AS_JSON,
+        "JSON matched",
+    );
+
+    let mut cbor_actual: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor_actual).expect("writing to vec<u8> succeeded");
+
+    assert_eq!(cbor_actual, AS_CBOR, "CBOR matched");
+}
+
+#[test]
+fn base32_deserialize() {
+    let fixture = fixture();
+
+    let json_actual: MyStruct =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing from JSON matched");
+
+    // Decoding is case-insensitive.
+    let json_actual: MyStruct =
+        serde_json::from_str(r#"{"array":"mzxw6ytboi======","vec":"mzxw6","id":"csqpyrk1"}"#)
+            .expect("deserializing lower case from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing lower case matched");
+
+    let cbor_actual: MyStruct =
+        ciborium::de::from_reader(&AS_CBOR[..]).expect("deserializing from CBOR succeeded");
+    assert_eq!(fixture, cbor_actual, "deserializing from CBOR matched");
+}
+
+/// Test vectors from RFC 4648 §10.
+#[test]
+fn base32_rfc4648_vectors() {
+    let vectors: &[(&str, &str, &str)] = &[
+        ("", "", ""),
+        ("f", "MY======", "CO======"),
+        ("fo", "MZXQ====", "CPNG===="),
+        ("foo", "MZXW6===", "CPNMU==="),
+        ("foob", "MZXW6YQ=", "CPNMUOG="),
+        ("fooba", "MZXW6YTB", "CPNMUOJ1"),
+        ("foobar", "MZXW6YTBOI======", "CPNMUOJ1E8======"),
+    ];
+
+    for &(bytes, standard, extended_hex) in vectors {
+        let vec = Base32Vec::from(bytes.as_bytes());
+        assert_eq!(vec.to_string(), standard);
+        assert_eq!(standard.parse::<Base32Vec>().unwrap(), vec);
+
+        let no_pad = standard.trim_end_matches('=');
+        assert_eq!(Base32NoPadVec::from(bytes.as_bytes()).to_string(), no_pad);
+        assert_eq!(*no_pad.parse::<Base32NoPadVec>().unwrap(), bytes.as_bytes());
+
+        let hex_vec = Base32HexVec::from(bytes.as_bytes());
+        assert_eq!(hex_vec.to_string(), extended_hex);
+        assert_eq!(extended_hex.parse::<Base32HexVec>().unwrap(), hex_vec);
+
+        let hex_no_pad = extended_hex.trim_end_matches('=');
+        assert_eq!(
+            Base32HexNoPadVec::from(bytes.as_bytes()).to_string(),
+            hex_no_pad
+        );
+        assert_eq!(
+            *hex_no_pad.parse::<Base32HexNoPadVec>().unwrap(),
+            bytes.as_bytes()
+        );
+    }
+}
+
+#[test]
+fn base32_crockford() {
+    let id = Base32CrockfordVec::from(&b"foobar"[..]);
+    assert_eq!(id.to_string(), "CSQPYRK1E8");
+    assert_eq!(format!("{id:?}"), "Base32CrockfordVec(CSQPYRK1E8)");
+
+    // I and L decode as 1, O as 0, and hyphens are ignored.
+    assert_eq!("csqp-yrkie8".parse::<Base32CrockfordVec>().unwrap(), id);
+    assert_eq!("CSQP-YRKLE8".parse::<Base32CrockfordVec>().unwrap(), id);
+    assert_eq!(
+        "0O".parse::<Base32CrockfordArray<1>>().unwrap(),
+        "00".parse::<Base32CrockfordArray<1>>().unwrap(),
+    );
+
+    // U is not in the alphabet.
+    assert_eq!(
+        "CSQPYRKUE8".parse::<Base32CrockfordVec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'U',
+            index: 7,
+        }),
+    );
+    // Padding is not allowed.
+    assert_eq!(
+        "CR======".parse::<Base32CrockfordVec>(),
+        Err(DecodeError::InvalidPadding),
+    );
+}
+
+#[test]
+fn base32_array_direct() {
+    let array = Base32Array::<6>::new(*b"foobar");
+    assert_eq!(array.to_string(), "MZXW6YTBOI======");
+    assert_eq!(format!("{array:?}"), "Base32Array(MZXW6YTBOI======)");
+    assert_eq!("MZXW6YTBOI======".parse::<Base32Array<6>>().unwrap(), array);
+
+    let json = serde_json::to_string(&array).expect("serialized");
+    assert_eq!(json, r#""MZXW6YTBOI======""#);
+    let error = serde_json::from_str::<Base32Array<5>>(&json).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 6, expected a byte array [u8; 5] at line 1 column 18",
+    );
+
+    let array = Base32NoPadArray::<6>::new(*b"foobar");
+    assert_eq!(array.to_string(), "MZXW6YTBOI");
+    assert_eq!("MZXW6YTBOI".parse::<Base32NoPadArray<6>>().unwrap(), array);
+    let array = Base32HexNoPadArray::<6>::new(*b"foobar");
+    assert_eq!(array.to_string(), "CPNMUOJ1E8");
+    assert_eq!(
+        "CPNMUOJ1E8".parse::<Base32HexNoPadArray<6>>().unwrap(),
+        array
+    );
+}
+
+#[test]
+fn base32_decode_errors() {
+    // Invalid numbers of symbols.
+    assert_eq!(
+        "MZX".parse::<Base32NoPadVec>(),
+        Err(DecodeError::InvalidEncodedLength { length: 3 }),
+    );
+    assert_eq!(
+        "M=======".parse::<Base32Vec>(),
+        Err(DecodeError::InvalidEncodedLength { length: 8 }),
+    );
+
+    // Missing, extraneous or unexpected padding.
+    assert_eq!(
+        "MZXW6".parse::<Base32Vec>(),
+        Err(DecodeError::InvalidPadding)
+    );
+    assert_eq!(
+        "MZXW6====".parse::<Base32Vec>(),
+        Err(DecodeError::InvalidPadding)
+    );
+    assert_eq!(
+        "MZXW6===".parse::<Base32NoPadVec>(),
+        Err(DecodeError::InvalidPadding),
+    );
+
+    // Invalid characters, including padding in the middle.
+    assert_eq!(
+        "MZXW1===".parse::<Base32Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '1',
+            index: 4,
+        }),
+    );
+    assert_eq!(
+        "MY==MZXW".parse::<Base32Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '=',
+            index: 2,
+        }),
+    );
+    assert_eq!(
+        "CPNMW===".parse::<Base32HexVec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'W',
+            index: 4,
+        }),
+    );
+
+    // Non-zero trailing bits.
+    assert_eq!(
+        "MZ======".parse::<Base32Vec>(),
+        Err(DecodeError::InvalidLastSymbol {
+            character: 'Z',
+            index: 1,
+        }),
+    );
+
+    let error = serde_json::from_str::<Base32Vec>(r#""MZXW6""#).unwrap_err();
+    assert_eq!(error.to_string(), "invalid padding at line 1 column 7");
+}
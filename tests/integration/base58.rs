@@ -0,0 +1,158 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{Base58Array, Base58CheckArray, Base58CheckVec, Base58Vec, DecodeError};
+
+// A version byte of zero followed by a public key hash: a Bitcoin P2PKH
+// address.
+static ADDRESS: [u8; 21] = hex!("00010966776006953D5567439E5E39F86A0D273BEE");
+static ADDRESS_STR: &str = "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM";
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    #[serde(with = "serde_human_bytes::base58_vec")]
+    data: Vec<u8>,
+    #[serde(with = "serde_human_bytes::base58_array::check")]
+    address: [u8; 21],
+}
+
+static AS_JSON: &str =
+    r#"{"data":"2NEpo7TZRRrLZSi2U","address":"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"}"#;
+
+fn fixture() -> MyStruct {
+    MyStruct {
+        data: b"Hello World!".to_vec(),
+        address: ADDRESS,
+    }
+}
+
+#[test]
+fn base58_serde() {
+    let fixture = fixture();
+
+    let json = serde_json::to_string(&fixture).expect("serializing as JSON succeeded");
+    assert_eq!(json, AS_JSON, "JSON matched");
+    let json_actual: MyStruct =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing from JSON matched");
+
+    // Non-human-readable formats use raw bytes, without the checksum.
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("writing to vec<u8> succeeded");
+    assert!(cbor.ends_with(&ADDRESS), "CBOR contains the raw address");
+    let cbor_actual: MyStruct =
+        ciborium::de::from_reader(&*cbor).expect("deserializing from CBOR succeeded");
+    assert_eq!(fixture, cbor_actual, "deserializing from CBOR matched");
+}
+
+#[test]
+fn base58_types() {
+    let vec = Base58Vec::from(&hex!("000000287fb4cd")[..]);
+    // Leading zero bytes are encoded as leading 1s.
+    assert_eq!(vec.to_string(), "111233QC4");
+    assert_eq!(format!("{vec:?}"), "Base58Vec(111233QC4)");
+    assert_eq!("111233QC4".parse::<Base58Vec>().unwrap(), vec);
+    assert_eq!("".parse::<Base58Vec>().unwrap(), Base58Vec::default());
+
+    let array = Base58Array::<7>::try_from("111233QC4").unwrap();
+    assert_eq!(array.into_inner(), hex!("000000287fb4cd"));
+
+    let address = Base58CheckArray::new(ADDRESS);
+    assert_eq!(address.to_string(), ADDRESS_STR);
+    assert_eq!(
+        ADDRESS_STR.parse::<Base58CheckArray<21>>().unwrap(),
+        address
+    );
+    assert_eq!(
+        *ADDRESS_STR.parse::<Base58CheckVec>().unwrap(),
+        ADDRESS.to_vec()
+    );
+
+    let json = serde_json::to_string(&address).expect("serialized");
+    assert_eq!(json, format!("\"{ADDRESS_STR}\""));
+    let error = serde_json::from_str::<Base58CheckArray<20>>(&json).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 21, expected a byte array [u8; 20] at line 1 column 35",
+    );
+}
+
+#[test]
+fn base58_array_lengths() {
+    // The longest encoding of each length decodes.
+    let max = Base58Array::new([0xff; 32]);
+    assert_eq!(max.to_string().parse::<Base58Array<32>>(), Ok(max));
+
+    // Strings that decode to the wrong length report it.
+    let short = Base58Vec::from(vec![0xff; 31]).to_string();
+    assert_eq!(
+        short.parse::<Base58Array<32>>(),
+        Err(DecodeError::InvalidLength {
+            expected: 32,
+            actual: 31,
+        }),
+    );
+    let long = Base58Vec::from(vec![0xff; 33]).to_string();
+    assert_eq!(
+        long.parse::<Base58Array<32>>(),
+        Err(DecodeError::InvalidLength {
+            expected: 32,
+            actual: 33,
+        }),
+    );
+
+    // Much longer strings are rejected before they're decoded.
+    let long = "2".repeat(1 << 20);
+    assert_eq!(
+        long.parse::<Base58Array<32>>(),
+        Err(DecodeError::InvalidEncodedLength { length: 1 << 20 }),
+    );
+    assert_eq!(
+        long.parse::<Base58CheckArray<32>>(),
+        Err(DecodeError::InvalidEncodedLength { length: 1 << 20 }),
+    );
+}
+
+#[test]
+fn base58_decode_errors() {
+    // 0, O, I and l are not in the alphabet.
+    assert_eq!(
+        "2NEpo7TZRRrLZSi2O".parse::<Base58Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'O',
+            index: 16,
+        }),
+    );
+    assert_eq!(
+        "2NEé".parse::<Base58Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'é',
+            index: 3,
+        }),
+    );
+
+    // A corrupted address fails the checksum, as does one that's too short
+    // to contain a checksum.
+    assert_eq!(
+        "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN".parse::<Base58CheckVec>(),
+        Err(DecodeError::InvalidChecksum),
+    );
+    assert_eq!(
+        "2NE".parse::<Base58CheckVec>(),
+        Err(DecodeError::InvalidChecksum)
+    );
+    // But an invalid character is reported as such.
+    assert_eq!(
+        "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjv0".parse::<Base58CheckVec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '0',
+            index: 32,
+        }),
+    );
+
+    let error = serde_json::from_str::<Base58CheckVec>(r#""16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN""#)
+        .unwrap_err();
+    assert_eq!(error.to_string(), "invalid checksum at line 1 column 35");
+}
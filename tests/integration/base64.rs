@@ -2,8 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use hex_literal::hex;
-use serde::{Deserialize, Serialize};
-use serde_human_bytes::Base64Vec;
+use serde::{Deserialize, Serialize, de::DeserializeSeed};
+use serde_human_bytes::{
+    Base64LenientVec, Base64NoPadVec, Base64UrlNoPadVec, Base64UrlVec, Base64Vec,
+};
+use serde_test::Configure;
+use std::marker::PhantomData;
 
 #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 struct MyStruct {
@@ -92,3 +96,318 @@ fn base64_vec_direct() {
     let roundtrip: WithBase64VecDirect = serde_json::from_str(&json).expect("deserialized");
     assert_eq!(fixture, roundtrip);
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct Alphabets {
+    #[serde(with = "serde_human_bytes::base64_vec")]
+    standard: Vec<u8>,
+    #[serde(with = "serde_human_bytes::base64_vec::standard_no_pad")]
+    standard_no_pad: Vec<u8>,
+    #[serde(with = "serde_human_bytes::base64_vec::url_safe")]
+    url_safe: Vec<u8>,
+    #[serde(with = "serde_human_bytes::base64_vec::url_safe_no_pad")]
+    url_safe_no_pad: Vec<u8>,
+}
+
+/// Test the alphabet-specific newtypes as field types.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct AlphabetsDirect {
+    standard: Base64Vec,
+    standard_no_pad: Base64NoPadVec,
+    url_safe: Base64UrlVec,
+    url_safe_no_pad: Base64UrlNoPadVec,
+}
+
+// Bytes chosen so that the encoding uses the alphabet-specific characters, and
+// needs padding.
+static ALPHABET_FIXTURE: &[u8] = &hex!("fbffbffbff");
+
+static ALPHABETS_AS_JSON: &str = r#"{"standard":"+/+/+/8=","standard_no_pad":"+/+/+/8","url_safe":"-_-_-_8=","url_safe_no_pad":"-_-_-_8"}"#;
+
+#[test]
+fn base64_alphabets() {
+    let fixture = Alphabets {
+        standard: ALPHABET_FIXTURE.to_vec(),
+        standard_no_pad: ALPHABET_FIXTURE.to_vec(),
+        url_safe: ALPHABET_FIXTURE.to_vec(),
+        url_safe_no_pad: ALPHABET_FIXTURE.to_vec(),
+    };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, ALPHABETS_AS_JSON);
+
+    let roundtrip: Alphabets = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+
+    let direct: AlphabetsDirect = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(
+        serde_json::to_string(&direct).expect("serialized"),
+        ALPHABETS_AS_JSON
+    );
+    assert_eq!(direct.standard.to_string(), "+/+/+/8=");
+    assert_eq!(direct.standard_no_pad.to_string(), "+/+/+/8");
+    assert_eq!(direct.url_safe.to_string(), "-_-_-_8=");
+    assert_eq!(direct.url_safe_no_pad.to_string(), "-_-_-_8");
+    assert_eq!(
+        format!("{:?}", direct.url_safe_no_pad),
+        "Base64UrlNoPadVec(-_-_-_8)"
+    );
+
+    // Each variant rejects the others' alphabet or padding.
+    for (field, value) in [
+        ("standard", "-_-_-_8="),
+        ("standard", "+/+/+/8"),
+        ("standard_no_pad", "+/+/+/8="),
+        ("url_safe", "+/+/+/8="),
+        ("url_safe", "-_-_-_8"),
+        ("url_safe_no_pad", "-_-_-_8="),
+    ] {
+        let mut json: serde_json::Value = serde_json::from_str(ALPHABETS_AS_JSON).unwrap();
+        json[field] = value.into();
+        serde_json::from_value::<Alphabets>(json)
+            .expect_err(&format!("{field} should reject {value:?}"));
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct Lenient {
+    #[serde(with = "serde_human_bytes::base64_vec::lenient")]
+    data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct LenientWhitespace {
+    #[serde(
+        serialize_with = "serde_human_bytes::base64_vec::lenient::serialize",
+        deserialize_with = "serde_human_bytes::base64_vec::lenient::deserialize_ignore_whitespace"
+    )]
+    data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct LenientDirect {
+    data: Base64LenientVec,
+}
+
+#[test]
+fn base64_lenient() {
+    for input in ["+/+/+/8=", "+/+/+/8", "-_-_-_8=", "-_-_-_8"] {
+        let json = format!(r#"{{"data":"{input}"}}"#);
+
+        let actual: Lenient = serde_json::from_str(&json).expect("deserialized");
+        assert_eq!(actual.data, ALPHABET_FIXTURE, "decoding {input:?}");
+        // Serialization is always canonical.
+        assert_eq!(
+            serde_json::to_string(&actual).expect("serialized"),
+            r#"{"data":"+/+/+/8="}"#,
+        );
+
+        let actual: LenientDirect = serde_json::from_str(&json).expect("deserialized");
+        assert_eq!(*actual.data, ALPHABET_FIXTURE, "decoding {input:?}");
+        assert_eq!(actual.data.to_string(), "+/+/+/8=");
+    }
+
+    // Mixed alphabets, whitespace and invalid characters are rejected.
+    for input in ["+/-_+/8=", "+/+/\n+/8=", "+/+/+/8!"] {
+        let json = serde_json::json!({ "data": input });
+        serde_json::from_value::<Lenient>(json.clone())
+            .expect_err(&format!("{input:?} should be rejected"));
+        serde_json::from_value::<LenientDirect>(json)
+            .expect_err(&format!("{input:?} should be rejected"));
+    }
+
+    // Binary formats are unaffected.
+    let mut cbor: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(
+        &Lenient {
+            data: FIXTURE.to_vec(),
+        },
+        &mut cbor,
+    )
+    .expect("serialized");
+    assert_eq!(cbor, AS_CBOR);
+    let actual: Lenient = ciborium::de::from_reader(&cbor[..]).expect("deserialized");
+    assert_eq!(actual.data, FIXTURE);
+}
+
+#[test]
+fn base64_lenient_ignore_whitespace() {
+    for input in [
+        "+/+/+/8=",
+        "+/+/\r\n+/8=",
+        " -_-_\n-_8 ",
+        "ASNFZ4mrze8B\nI0VniavN7w==\n",
+    ] {
+        let json = serde_json::json!({ "data": input });
+        let actual: LenientWhitespace = serde_json::from_value(json).expect("deserialized");
+        let expected = if input.contains('A') {
+            FIXTURE
+        } else {
+            ALPHABET_FIXTURE
+        };
+        assert_eq!(actual.data, expected, "decoding {input:?}");
+    }
+
+    serde_json::from_str::<LenientWhitespace>(r#"{"data":"+/+/ +/8!"}"#)
+        .expect_err("invalid characters are rejected");
+
+    // Errors report indexes in the original input, including whitespace.
+    let error = serde_json::from_value::<LenientWhitespace>(
+        serde_json::json!({ "data": "  \n  AAAA!AAA" }),
+    )
+    .unwrap_err();
+    assert_eq!(error.to_string(), "invalid character '!' at index 9");
+}
+
+/// Deserializes JSON as if it were a non-human-readable format.
+fn from_json_compact<T>(json: &str) -> T
+where
+    T: for<'de> Deserialize<'de>,
+{
+    PhantomData::<T>
+        .compact()
+        .deserialize(&mut serde_json::Deserializer::from_str(json))
+        .expect("deserialized from JSON")
+}
+
+/// Round-trips `value` through each non-human-readable format we test against,
+/// returning the decoded values.
+fn binary_roundtrips<T>(value: &T) -> Vec<(&'static str, T)>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    let json = serde_json::to_string(&value.compact()).expect("serialized JSON");
+    let from_json = from_json_compact(&json);
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(value, &mut cbor).expect("serialized CBOR");
+    let from_cbor = ciborium::de::from_reader(&cbor[..]).expect("deserialized from CBOR");
+
+    let msgpack = rmp_serde::to_vec(value).expect("serialized MessagePack");
+    let from_msgpack = rmp_serde::from_slice(&msgpack).expect("deserialized from MessagePack");
+
+    let postcard = postcard::to_allocvec(value).expect("serialized postcard");
+    let from_postcard = postcard::from_bytes(&postcard).expect("deserialized from postcard");
+
+    vec![
+        ("JSON (compact)", from_json),
+        ("CBOR", from_cbor),
+        ("MessagePack", from_msgpack),
+        ("postcard", from_postcard),
+    ]
+}
+
+#[test]
+fn base64_vec_binary_formats() {
+    // Include a payload larger than the scratch buffers some formats use for
+    // borrowed bytes.
+    for len in [0, 16, 10_000] {
+        let fixture = WithBase64VecDirect {
+            data: Base64Vec::new((0..len).map(|i| i as u8).collect()),
+        };
+        for (format, actual) in binary_roundtrips(&fixture) {
+            assert_eq!(actual, fixture, "{format} round-trip of {len} bytes");
+        }
+
+        let fixture = MyStruct {
+            data: fixture.data.into_inner(),
+        };
+        for (format, actual) in binary_roundtrips(&fixture) {
+            assert_eq!(actual, fixture, "{format} round-trip of {len} bytes");
+        }
+    }
+}
+
+#[test]
+fn base64_vec_deserialize_seq() {
+    /// The same layout as `MyStruct`, but with serde's default representation
+    /// for `Vec<u8>`: a sequence of integers.
+    #[derive(Serialize)]
+    struct Plain {
+        data: Vec<u8>,
+    }
+
+    let plain = Plain {
+        data: FIXTURE.to_vec(),
+    };
+
+    let json = serde_json::to_string(&plain).expect("serialized JSON");
+    let actual: MyStruct = from_json_compact(&json);
+    assert_eq!(actual, fixture(), "JSON");
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&plain, &mut cbor).expect("serialized CBOR");
+    let actual: MyStruct = ciborium::de::from_reader(&cbor[..]).expect("deserialized from CBOR");
+    assert_eq!(actual, fixture(), "CBOR");
+
+    let msgpack = rmp_serde::to_vec(&plain).expect("serialized MessagePack");
+    let actual: MyStruct = rmp_serde::from_slice(&msgpack).expect("deserialized from MessagePack");
+    assert_eq!(actual, fixture(), "MessagePack");
+}
+
+#[test]
+fn base64_vec_from_str() {
+    use serde_human_bytes::DecodeError;
+
+    let expected = Base64Vec::new(FIXTURE.to_vec());
+    assert_eq!(
+        "ASNFZ4mrze8BI0VniavN7w==".parse::<Base64Vec>(),
+        Ok(expected.clone()),
+    );
+    assert_eq!(
+        expected.to_string().parse::<Base64Vec>(),
+        Ok(expected.clone())
+    );
+    assert_eq!(Base64Vec::from(FIXTURE), expected);
+
+    assert_eq!(
+        Base64Vec::try_from("ASNFZ4mrze8BI0VniavN7w"),
+        Err(DecodeError::InvalidPadding),
+    );
+    assert_eq!(
+        Base64Vec::try_from("ASNFZ4mrze8BI0Vn-avN7w=="),
+        Err(DecodeError::InvalidCharacter {
+            character: '-',
+            index: 16,
+        }),
+    );
+    assert_eq!(
+        Base64Vec::try_from("ASNFZ"),
+        Err(DecodeError::InvalidEncodedLength { length: 5 }),
+    );
+    let err = Base64Vec::try_from("ASNFZ5==").expect_err("trailing bits");
+    assert_eq!(
+        err,
+        DecodeError::InvalidLastSymbol {
+            character: '5',
+            index: 5,
+        }
+    );
+
+    // Each variant parses what it displays.
+    let bytes = ALPHABET_FIXTURE.to_vec();
+    let value = Base64NoPadVec::new(bytes.clone());
+    assert_eq!(value.to_string().parse(), Ok(value));
+    let value = Base64UrlVec::new(bytes.clone());
+    assert_eq!(value.to_string().parse(), Ok(value));
+    let value = Base64UrlNoPadVec::new(bytes.clone());
+    assert_eq!(value.to_string().parse(), Ok(value));
+    assert_eq!(
+        "-_-_-_8".parse::<Base64LenientVec>(),
+        Ok(Base64LenientVec::new(bytes)),
+    );
+}
+
+#[test]
+fn base64_vec_deserialize_errors() {
+    let err = serde_json::from_str::<MyStruct>(r#"{"data":"ASNFZ4mrze8BI0Vn-avN7w=="}"#)
+        .expect_err("invalid character");
+    assert_eq!(
+        err.to_string(),
+        "invalid character '-' at index 16 at line 1 column 34",
+    );
+
+    let err = serde_json::from_str::<MyStruct>(r#"{"data":"ASNFZ4mrze8BI0VniavN7w"}"#)
+        .expect_err("missing padding");
+    assert_eq!(err.to_string(), "invalid padding at line 1 column 32");
+}
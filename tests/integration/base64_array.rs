@@ -0,0 +1,178 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::Base64Array;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    #[serde(with = "serde_human_bytes::base64_array")]
+    x: [u8; 16],
+}
+
+/// Test that `Base64Array` works with `#[serde(with = "...")]`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithBase64ArrayAttr {
+    #[serde(with = "Base64Array::<16>")]
+    x: [u8; 16],
+}
+
+/// Test using `Base64Array` directly as a field type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithBase64ArrayDirect {
+    x: Base64Array<16>,
+}
+
+static FIXTURE: MyStruct = MyStruct {
+    x: hex!("0123456789abcdef0123456789abcdef"),
+};
+
+static AS_JSON: &str = r#"{"x":"ASNFZ4mrze8BI0VniavN7w=="}"#;
+static AS_CBOR: [u8; 20] = hex!("a16178500123456789abcdef0123456789abcdef");
+
+#[test]
+fn base64_array_serialize() {
+    assert_eq!(
+        serde_json::to_string(&FIXTURE).expect("serializing as JSON succeeded"),
+        AS_JSON,
+        "JSON matched",
+    );
+    let mut cbor_actual: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&FIXTURE, &mut cbor_actual).expect("writing to vec<u8> succeeded");
+
+    assert_eq!(cbor_actual, AS_CBOR, "CBOR matched");
+}
+
+#[test]
+fn base64_array_deserialize() {
+    let json_actual: MyStruct =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(FIXTURE, json_actual, "deserializing from JSON matched");
+
+    let cbor_actual: MyStruct =
+        ciborium::de::from_reader(&AS_CBOR[..]).expect("deserializing from CBOR succeeded");
+    assert_eq!(FIXTURE, cbor_actual, "deserializing from CBOR succeeded");
+}
+
+#[test]
+fn base64_array_deserialize_wrong_length() {
+    // 15 bytes.
+    let err = serde_json::from_str::<MyStruct>(r#"{"x":"ASNFZ4mrze8BI0VniavN"}"#)
+        .expect_err("too short rejected");
+    assert_eq!(
+        err.to_string(),
+        "invalid length 15, expected a byte array [u8; 16] at line 1 column 27",
+    );
+
+    // 17 bytes.
+    let err = serde_json::from_str::<MyStruct>(r#"{"x":"ASNFZ4mrze8BI0VniavN7wE="}"#)
+        .expect_err("too long rejected");
+    assert_eq!(
+        err.to_string(),
+        "invalid length 17, expected a byte array [u8; 16] at line 1 column 31",
+    );
+
+    // 15 bytes as CBOR.
+    let cbor = hex!("a161784f0123456789abcdef0123456789abcd");
+    let err = ciborium::de::from_reader::<MyStruct, _>(&cbor[..]).expect_err("too short rejected");
+    assert!(
+        err.to_string()
+            .contains("invalid length 15, expected a byte array [u8; 16]"),
+        "unexpected error: {err}",
+    );
+
+    serde_json::from_str::<MyStruct>(r#"{"x":"ASNFZ4mrze8BI0VniavN7w=!"}"#)
+        .expect_err("invalid base64 rejected");
+}
+
+#[test]
+fn base64_array_with_attr() {
+    let fixture = WithBase64ArrayAttr {
+        x: hex!("0123456789abcdef0123456789abcdef"),
+    };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, AS_JSON);
+
+    let roundtrip: WithBase64ArrayAttr = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+}
+
+#[test]
+fn base64_array_direct() {
+    let fixture = WithBase64ArrayDirect {
+        x: Base64Array::new(hex!("0123456789abcdef0123456789abcdef")),
+    };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, AS_JSON);
+
+    let roundtrip: WithBase64ArrayDirect = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+
+    assert_eq!(
+        format!("{:?}", fixture.x),
+        "Base64Array(ASNFZ4mrze8BI0VniavN7w==)"
+    );
+    assert_eq!(fixture.x.to_string(), "ASNFZ4mrze8BI0VniavN7w==");
+}
+
+#[test]
+fn base64_array_deserialize_seq() {
+    #[derive(Serialize)]
+    struct Plain {
+        x: [u8; 16],
+    }
+
+    let mut cbor: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&Plain { x: FIXTURE.x }, &mut cbor).expect("serialized");
+    assert_ne!(cbor, AS_CBOR);
+
+    let actual: MyStruct = ciborium::de::from_reader(&cbor[..]).expect("deserialized");
+    assert_eq!(actual, FIXTURE);
+}
+
+#[test]
+fn base64_array_from_str() {
+    use serde_human_bytes::DecodeError;
+
+    let expected = Base64Array::new(FIXTURE.x);
+    assert_eq!(
+        "ASNFZ4mrze8BI0VniavN7w==".parse::<Base64Array<16>>(),
+        Ok(expected),
+    );
+    assert_eq!(
+        expected.to_string().parse::<Base64Array<16>>(),
+        Ok(expected)
+    );
+    assert_eq!(
+        Base64Array::<16>::try_from("ASNFZ4mrze8BI0VniavN"),
+        Err(DecodeError::InvalidLength {
+            expected: 16,
+            actual: 15,
+        }),
+    );
+    assert_eq!(
+        Base64Array::<16>::try_from("ASNFZ4mrze8BI0VniavN7wE="),
+        Err(DecodeError::InvalidLength {
+            expected: 16,
+            actual: 17,
+        }),
+    );
+    assert_eq!(
+        Base64Array::<16>::try_from("ASNFZ4mrze8BI0Vn!avN7w=="),
+        Err(DecodeError::InvalidCharacter {
+            character: '!',
+            index: 16,
+        }),
+    );
+    assert_eq!(Base64Array::<16>::try_from(&FIXTURE.x[..]), Ok(expected));
+    assert_eq!(
+        Base64Array::<16>::try_from(&FIXTURE.x[..4]),
+        Err(DecodeError::InvalidLength {
+            expected: 16,
+            actual: 4,
+        }),
+    );
+}
@@ -0,0 +1,193 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{BoundedBase64Vec, DecodeError};
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    #[serde(
+        serialize_with = "serde_human_bytes::base64_vec::serialize",
+        deserialize_with = "serde_human_bytes::base64_vec::deserialize_max::<_, 16>"
+    )]
+    data: Vec<u8>,
+}
+
+/// Test that `BoundedBase64Vec` works with `#[serde(with = "...")]`.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithBoundedAttr {
+    #[serde(with = "BoundedBase64Vec::<16>")]
+    data: Vec<u8>,
+}
+
+/// Test using `BoundedBase64Vec` directly as a field type.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithBoundedDirect {
+    data: BoundedBase64Vec<16>,
+}
+
+/// The same layout as the structs above, but with serde's default
+/// representation for `Vec<u8>`: a sequence of integers.
+#[derive(Serialize)]
+struct Plain {
+    data: Vec<u8>,
+}
+
+static FIXTURE: &[u8] = &hex!("0123456789abcdef0123456789abcdef");
+
+static AS_JSON: &str = r#"{"data":"ASNFZ4mrze8BI0VniavN7w=="}"#;
+// 17 bytes.
+static TOO_LONG_JSON: &str = r#"{"data":"ASNFZ4mrze8BI0VniavN7wE="}"#;
+
+#[test]
+fn base64_bounded_roundtrip() {
+    let fixture = MyStruct {
+        data: FIXTURE.to_vec(),
+    };
+    assert_eq!(
+        serde_json::to_string(&fixture).expect("serialized"),
+        AS_JSON
+    );
+    let actual: MyStruct = serde_json::from_str(AS_JSON).expect("deserialized");
+    assert_eq!(actual, fixture);
+
+    let actual: WithBoundedAttr = serde_json::from_str(AS_JSON).expect("deserialized");
+    assert_eq!(actual.data, FIXTURE);
+
+    let actual: WithBoundedDirect = serde_json::from_str(AS_JSON).expect("deserialized");
+    assert_eq!(*actual.data, FIXTURE);
+    assert_eq!(serde_json::to_string(&actual).expect("serialized"), AS_JSON);
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("serialized");
+    let actual: WithBoundedDirect = ciborium::de::from_reader(&cbor[..]).expect("deserialized");
+    assert_eq!(*actual.data, FIXTURE);
+
+    // Shorter input is fine.
+    let actual: WithBoundedDirect =
+        serde_json::from_str(r#"{"data":"ASNF"}"#).expect("deserialized");
+    assert_eq!(*actual.data, [0x01, 0x23, 0x45]);
+}
+
+#[test]
+fn base64_bounded_too_long() {
+    let err = serde_json::from_str::<MyStruct>(TOO_LONG_JSON).expect_err("too long");
+    assert_eq!(
+        err.to_string(),
+        "invalid length 17, expected a base64-encoded string at most 16 bytes long \
+         at line 1 column 34",
+    );
+    serde_json::from_str::<WithBoundedAttr>(TOO_LONG_JSON).expect_err("too long");
+    serde_json::from_str::<WithBoundedDirect>(TOO_LONG_JSON).expect_err("too long");
+
+    // The length check happens before decoding, so even invalid base64 is
+    // reported as too long.
+    let json = format!(r#"{{"data":"{}"}}"#, "!".repeat(1 << 20));
+    let err = serde_json::from_str::<MyStruct>(&json).expect_err("too long");
+    assert!(
+        err.to_string().starts_with("invalid length 786432,"),
+        "unexpected error: {err}",
+    );
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&MyStruct { data: vec![0; 17] }, &mut cbor).expect("serialized");
+    let err = ciborium::de::from_reader::<MyStruct, _>(&cbor[..]).expect_err("too long");
+    assert!(
+        err.to_string()
+            .contains("invalid length 17, expected a byte array at most 16 bytes long"),
+        "unexpected error: {err}",
+    );
+
+    let msgpack = rmp_serde::to_vec(&Plain { data: vec![0; 20] }).expect("serialized");
+    let err = rmp_serde::from_slice::<WithBoundedDirect>(&msgpack).expect_err("too long");
+    assert!(
+        err.to_string()
+            .contains("invalid length 20, expected a byte array at most 16 bytes long"),
+        "unexpected error: {err}",
+    );
+
+    // An indefinite-length CBOR array has no length hint. It's rejected at
+    // the 17th element, before the truncated input after it is read.
+    let mut cbor = hex!("a1 64 64617461 9f").to_vec();
+    cbor.extend([0; 17]);
+    let err = ciborium::de::from_reader::<MyStruct, _>(&cbor[..]).expect_err("too long");
+    assert!(
+        err.to_string()
+            .contains("invalid length 17, expected a byte array at most 16 bytes long"),
+        "unexpected error: {err}",
+    );
+
+    let msgpack = rmp_serde::to_vec(&Plain { data: vec![0; 16] }).expect("serialized");
+    let actual: WithBoundedDirect = rmp_serde::from_slice(&msgpack).expect("deserialized");
+    assert_eq!(*actual.data, [0; 16]);
+}
+
+/// Padding counts towards the length limit, so a long run of `=` is
+/// rejected before anything is decoded.
+#[test]
+fn base64_bounded_padding() {
+    for data in ["=".repeat(100_000), format!("ASNF{}", "=".repeat(99_996))] {
+        let err = data.parse::<BoundedBase64Vec<16>>().expect_err("too long");
+        assert_eq!(
+            err,
+            DecodeError::TooLong {
+                max: 16,
+                actual: 75_000,
+            }
+        );
+
+        let json = format!(r#"{{"data":"{data}"}}"#);
+        let err = serde_json::from_str::<MyStruct>(&json).expect_err("too long");
+        assert!(
+            err.to_string().starts_with(
+                "invalid length 75000, expected a base64-encoded string at most 16 bytes long"
+            ),
+            "unexpected error: {err}",
+        );
+        serde_json::from_str::<WithBoundedDirect>(&json).expect_err("too long");
+    }
+
+    // Padding up to the encoded length of `MAX` bytes reaches the decoder,
+    // which rejects it as invalid.
+    let data = format!("ASNF{}", "=".repeat(20));
+    assert_eq!(
+        data.parse::<BoundedBase64Vec<16>>(),
+        Err(DecodeError::InvalidEncodedLength { length: 24 }),
+    );
+}
+
+#[test]
+fn base64_bounded_conversions() {
+    let expected = BoundedBase64Vec::<16>::new(FIXTURE.to_vec()).expect("not too long");
+    assert_eq!(
+        "ASNFZ4mrze8BI0VniavN7w==".parse::<BoundedBase64Vec<16>>(),
+        Ok(expected.clone()),
+    );
+    assert_eq!(expected.to_string(), "ASNFZ4mrze8BI0VniavN7w==");
+    assert_eq!(
+        format!("{expected:?}"),
+        "BoundedBase64Vec(ASNFZ4mrze8BI0VniavN7w==)"
+    );
+    assert_eq!(BoundedBase64Vec::<16>::try_from(FIXTURE), Ok(expected));
+
+    let err = BoundedBase64Vec::<16>::try_from("ASNFZ4mrze8BI0VniavN7wE=").expect_err("too long");
+    assert_eq!(
+        err,
+        DecodeError::TooLong {
+            max: 16,
+            actual: 17,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid length 17, expected at most 16 bytes"
+    );
+    assert_eq!(
+        BoundedBase64Vec::<16>::new(vec![0; 17]),
+        Err(DecodeError::TooLong {
+            max: 16,
+            actual: 17,
+        }),
+    );
+}
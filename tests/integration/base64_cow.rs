@@ -0,0 +1,156 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::Base64Cow;
+use std::borrow::Cow;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct<'a> {
+    #[serde(borrow, with = "serde_human_bytes::base64_cow")]
+    data: Cow<'a, [u8]>,
+}
+
+/// Test that `Base64Cow` works with `#[serde(with = "...")]`.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithBase64CowAttr<'a> {
+    #[serde(borrow, with = "Base64Cow")]
+    data: Cow<'a, [u8]>,
+}
+
+/// Test using `Base64Cow` directly as a field type.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithBase64CowDirect<'a> {
+    #[serde(borrow)]
+    data: Base64Cow<'a>,
+}
+
+static FIXTURE: &[u8] = &hex!("0123456789abcdef0123456789abcdef");
+
+static AS_JSON: &str = r#"{"data":"ASNFZ4mrze8BI0VniavN7w=="}"#;
+
+// CBOR: map with one key "data" and a byte string value.
+static AS_CBOR: [u8; 23] = hex!("a1646461746150 0123456789abcdef0123456789abcdef");
+
+fn fixture() -> MyStruct<'static> {
+    MyStruct {
+        data: Cow::Borrowed(FIXTURE),
+    }
+}
+
+#[test]
+fn base64_cow_serialize() {
+    let fixture = fixture();
+
+    assert_eq!(
+        serde_json::to_string(&fixture).expect("serializing as JSON succeeded"),
+        AS_JSON,
+        "JSON matched",
+    );
+
+    let mut cbor_actual: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor_actual).expect("writing to vec<u8> succeeded");
+
+    assert_eq!(cbor_actual, AS_CBOR, "CBOR matched");
+}
+
+#[test]
+fn base64_cow_deserialize() {
+    let fixture = fixture();
+
+    // Base64 text always has to be decoded into a new buffer.
+    let json_actual: MyStruct =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing from JSON matched");
+    assert!(matches!(json_actual.data, Cow::Owned(_)));
+
+    // Deserializing from a reader can't lend out the input.
+    let msgpack = rmp_serde::to_vec(&fixture).expect("serialized MessagePack");
+    let mut deserializer = rmp_serde::Deserializer::new(&msgpack[..]);
+    let msgpack_actual =
+        MyStruct::deserialize(&mut deserializer).expect("deserializing from MessagePack succeeded");
+    assert_eq!(
+        fixture, msgpack_actual,
+        "deserializing from MessagePack matched"
+    );
+    assert!(matches!(msgpack_actual.data, Cow::Owned(_)));
+}
+
+#[test]
+fn base64_cow_borrows() {
+    let fixture = fixture();
+
+    let postcard = postcard::to_allocvec(&fixture).expect("serialized postcard");
+    let actual: MyStruct = postcard::from_bytes(&postcard).expect("deserialized from postcard");
+    assert_eq!(actual, fixture);
+    let Cow::Borrowed(borrowed) = actual.data else {
+        panic!("postcard should borrow from the input");
+    };
+    assert!(
+        postcard.as_ptr_range().contains(&borrowed.as_ptr()),
+        "borrowed slice points into the input buffer",
+    );
+
+    let msgpack = rmp_serde::to_vec(&fixture).expect("serialized MessagePack");
+    let actual: WithBase64CowDirect =
+        rmp_serde::from_slice(&msgpack).expect("deserialized from MessagePack");
+    assert_eq!(*actual.data, fixture.data);
+    assert!(matches!(*actual.data, Cow::Borrowed(_)));
+
+    let actual: WithBase64CowAttr =
+        rmp_serde::from_slice(&msgpack).expect("deserialized from MessagePack");
+    assert_eq!(actual.data, fixture.data);
+    assert!(matches!(actual.data, Cow::Borrowed(_)));
+}
+
+#[test]
+fn base64_cow_deserialize_seq() {
+    #[derive(Serialize)]
+    struct Plain {
+        data: Vec<u8>,
+    }
+
+    let msgpack = rmp_serde::to_vec(&Plain {
+        data: FIXTURE.to_vec(),
+    })
+    .expect("serialized MessagePack");
+    let actual: MyStruct = rmp_serde::from_slice(&msgpack).expect("deserialized from MessagePack");
+    assert_eq!(actual, fixture());
+    assert!(matches!(actual.data, Cow::Owned(_)));
+}
+
+#[test]
+fn base64_cow_with_attr() {
+    let fixture = WithBase64CowAttr {
+        data: Cow::Borrowed(FIXTURE),
+    };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, AS_JSON);
+
+    let roundtrip: WithBase64CowAttr = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+}
+
+#[test]
+fn base64_cow_direct() {
+    let fixture = WithBase64CowDirect {
+        data: Base64Cow::from(FIXTURE),
+    };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, AS_JSON);
+
+    let roundtrip: WithBase64CowDirect = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+
+    assert_eq!(
+        format!("{:?}", fixture.data),
+        "Base64Cow(ASNFZ4mrze8BI0VniavN7w==)"
+    );
+    assert_eq!(fixture.data.to_string(), "ASNFZ4mrze8BI0VniavN7w==");
+
+    let owned: Base64Cow<'static> = fixture.data.into_owned();
+    assert!(matches!(*owned, Cow::Owned(_)));
+}
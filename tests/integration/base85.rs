@@ -0,0 +1,165 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{Ascii85Array, Ascii85Vec, DecodeError, Z85Array, Z85Vec};
+
+// The test vector from the Z85 specification.
+static HELLO_WORLD: [u8; 8] = hex!("864FD26FB559F75B");
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    #[serde(with = "serde_human_bytes::base85_array")]
+    key: [u8; 8],
+    #[serde(with = "serde_human_bytes::base85_vec::ascii85")]
+    data: Vec<u8>,
+}
+
+static AS_JSON: &str = r#"{"key":"HelloWorld","data":"9jqo^BlbD-BleB1DJ+*+F(f,q"}"#;
+
+fn fixture() -> MyStruct {
+    MyStruct {
+        key: HELLO_WORLD,
+        data: b"Man is distinguished".to_vec(),
+    }
+}
+
+#[test]
+fn base85_serde() {
+    let fixture = fixture();
+
+    let json = serde_json::to_string(&fixture).expect("serializing as JSON succeeded");
+    assert_eq!(json, AS_JSON, "JSON matched");
+    let json_actual: MyStruct =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing from JSON matched");
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("writing to vec<u8> succeeded");
+    assert!(
+        cbor.ends_with(b"Man is distinguished"),
+        "CBOR contains raw bytes"
+    );
+    let cbor_actual: MyStruct =
+        ciborium::de::from_reader(&*cbor).expect("deserializing from CBOR succeeded");
+    assert_eq!(fixture, cbor_actual, "deserializing from CBOR matched");
+}
+
+#[test]
+fn base85_types() {
+    let array = Z85Array::new(HELLO_WORLD);
+    assert_eq!(array.to_string(), "HelloWorld");
+    assert_eq!(format!("{array:?}"), "Z85Array(HelloWorld)");
+    assert_eq!("HelloWorld".parse::<Z85Array<8>>().unwrap(), array);
+    assert_eq!(*"HelloWorld".parse::<Z85Vec>().unwrap(), HELLO_WORLD);
+    assert_eq!("".parse::<Z85Vec>().unwrap(), Z85Vec::default());
+
+    // Groups of zeros are shortened to `z`, and a final partial group takes
+    // one more symbol than it has bytes.
+    let vec = Ascii85Vec::from(&hex!("00000000010203")[..]);
+    assert_eq!(vec.to_string(), "z!<N?");
+    assert_eq!(format!("{vec:?}"), "Ascii85Vec(z!<N?)");
+    assert_eq!("z!<N?".parse::<Ascii85Vec>().unwrap(), vec);
+    assert_eq!(Ascii85Array::new([0xff]).to_string(), "rr");
+    assert_eq!("BE".parse::<Ascii85Array<1>>().unwrap().into_inner(), *b"h");
+}
+
+#[test]
+fn z85_length_errors() {
+    // Z85 only encodes multiples of 4 bytes.
+    let error = serde_json::to_string(&Z85Vec::from(&b"hello"[..])).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 5, expected a multiple of 4 bytes",
+    );
+    // `Debug` and `Display` fall back to hex rather than failing. (Arrays of
+    // the wrong length don't compile.)
+    assert_eq!(
+        format!("{:?}", Z85Vec::from(&b"hello"[..])),
+        "Z85Vec(<invalid length 5: 68656c6c6f>)",
+    );
+    assert_eq!(
+        Z85Vec::from(vec![1, 2, 3]).to_string(),
+        "<invalid length 3: 010203>",
+    );
+
+    // Non-human-readable formats don't have this restriction.
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&Z85Vec::from(&b"hello"[..]), &mut cbor)
+        .expect("writing to vec<u8> succeeded");
+
+    assert_eq!(
+        "Hello".parse::<Z85Vec>(),
+        Ok(Z85Vec::from(&hex!("864FD26F")[..])),
+    );
+    assert_eq!(
+        "HelloWorl".parse::<Z85Vec>(),
+        Err(DecodeError::InvalidEncodedLength { length: 9 }),
+    );
+    let error = serde_json::from_str::<Z85Array<4>>(r#""HelloWorld""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 8, expected a byte array [u8; 4] at line 1 column 12",
+    );
+}
+
+#[test]
+fn base85_decode_errors() {
+    assert_eq!(
+        "Hello\"orld".parse::<Z85Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '"',
+            index: 5,
+        }),
+    );
+    assert_eq!(
+        "9jqo^Bl~D-".parse::<Ascii85Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '~',
+            index: 7,
+        }),
+    );
+    // `z` is only allowed at the start of a group.
+    assert_eq!(
+        "9jzo^".parse::<Ascii85Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'z',
+            index: 2,
+        }),
+    );
+    // Groups whose value doesn't fit in 4 bytes.
+    assert_eq!(
+        "Hello#####".parse::<Z85Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '#',
+            index: 5,
+        }),
+    );
+    assert_eq!(
+        "uuuuu".parse::<Ascii85Vec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'u',
+            index: 0,
+        }),
+    );
+    // A single trailing symbol can't encode a byte, and a final partial
+    // group must be encoded canonically.
+    assert_eq!(
+        "9jqo^B".parse::<Ascii85Vec>(),
+        Err(DecodeError::InvalidEncodedLength { length: 6 }),
+    );
+    assert_eq!(
+        "BQ".parse::<Ascii85Vec>(),
+        Err(DecodeError::InvalidLastSymbol {
+            character: 'Q',
+            index: 1,
+        }),
+    );
+
+    let error = serde_json::from_str::<Ascii85Vec>(r#""BQ""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid last symbol 'Q' at index 1: trailing bits must be zero at line 1 column 4",
+    );
+}
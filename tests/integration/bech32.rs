@@ -0,0 +1,252 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{
+    Bech32Array, Bech32Vec, Bech32mArray, Bech32mVec, DecodeError, FoundPrefix,
+    encoding::{Bech32Hrp, Encoding},
+};
+
+enum Abcdef {}
+
+impl Bech32Hrp for Abcdef {
+    const HRP: &'static str = "abcdef";
+}
+
+enum A {}
+
+impl Bech32Hrp for A {
+    const HRP: &'static str = "a";
+}
+
+// Test vectors from BIP 173 and BIP 350: the symbols in order, and in
+// reverse order.
+static BECH32: &str = "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw";
+static BECH32_BYTES: [u8; 20] = hex!("00443214c74254b635cf84653a56d7c675be77df");
+static BECH32M: &str = "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx";
+static BECH32M_BYTES: [u8; 20] = hex!("ffbbcdeb38bdab49ca307b9ac5a928398a418820");
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    key: Bech32Array<Abcdef, 20>,
+    #[serde(with = "Bech32mVec::<Abcdef>")]
+    data: Vec<u8>,
+}
+
+fn fixture() -> MyStruct {
+    MyStruct {
+        key: Bech32Array::new(BECH32_BYTES),
+        data: BECH32M_BYTES.to_vec(),
+    }
+}
+
+#[test]
+fn bech32_serde() {
+    let fixture = fixture();
+    let as_json = format!(r#"{{"key":"{BECH32}","data":"{BECH32M}"}}"#);
+
+    let json = serde_json::to_string(&fixture).expect("serializing as JSON succeeded");
+    assert_eq!(json, as_json, "JSON matched");
+    let json_actual: MyStruct =
+        serde_json::from_str(&as_json).expect("deserializing from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing from JSON matched");
+
+    // Non-human-readable formats use raw bytes, without the prefix or
+    // checksum.
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("writing to vec<u8> succeeded");
+    assert!(
+        cbor.ends_with(&BECH32M_BYTES),
+        "CBOR contains the raw bytes"
+    );
+    let cbor_actual: MyStruct =
+        ciborium::de::from_reader(&*cbor).expect("deserializing from CBOR succeeded");
+    assert_eq!(fixture, cbor_actual, "deserializing from CBOR matched");
+}
+
+/// The same as `MyStruct`, using the `bech32_array` and `bech32_vec`
+/// functions on plain fields.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithModules {
+    #[serde(
+        serialize_with = "serde_human_bytes::bech32_array::serialize::<Abcdef, _>",
+        deserialize_with = "serde_human_bytes::bech32_array::deserialize::<Abcdef, _, 20>"
+    )]
+    key: [u8; 20],
+    #[serde(
+        serialize_with = "serde_human_bytes::bech32_vec::bech32m::serialize::<Abcdef, _>",
+        deserialize_with = "serde_human_bytes::bech32_vec::bech32m::deserialize::<Abcdef, _>"
+    )]
+    data: Vec<u8>,
+}
+
+#[test]
+fn bech32_modules() {
+    let value = WithModules {
+        key: BECH32_BYTES,
+        data: BECH32M_BYTES.to_vec(),
+    };
+    let as_json = format!(r#"{{"key":"{BECH32}","data":"{BECH32M}"}}"#);
+
+    let json = serde_json::to_string(&value).expect("serializing as JSON succeeded");
+    assert_eq!(json, as_json, "JSON matched");
+    let json_actual: WithModules =
+        serde_json::from_str(&as_json).expect("deserializing from JSON succeeded");
+    assert_eq!(value, json_actual, "deserializing from JSON matched");
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&value, &mut cbor).expect("writing to vec<u8> succeeded");
+    let mut alias_cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture(), &mut alias_cbor).expect("writing to vec<u8> succeeded");
+    assert_eq!(cbor, alias_cbor, "CBOR matched the aliases");
+    let cbor_actual: WithModules =
+        ciborium::de::from_reader(&*cbor).expect("deserializing from CBOR succeeded");
+    assert_eq!(value, cbor_actual, "deserializing from CBOR matched");
+
+    // The checksum variant is part of the module path.
+    let swapped = format!(r#"{{"key":"{BECH32M}","data":"{BECH32}"}}"#);
+    let error = serde_json::from_str::<WithModules>(&swapped).unwrap_err();
+    assert!(
+        error.to_string().starts_with("invalid checksum"),
+        "unexpected error: {error}",
+    );
+}
+
+#[test]
+fn bech32_types() {
+    let key = Bech32Array::<Abcdef, 20>::new(BECH32_BYTES);
+    assert_eq!(key.to_string(), BECH32);
+    assert_eq!(format!("{key:?}"), format!("Bech32Array({BECH32})"));
+    assert_eq!(BECH32.parse::<Bech32Array<Abcdef, 20>>().unwrap(), key);
+    assert_eq!(*BECH32.parse::<Bech32Vec<Abcdef>>().unwrap(), BECH32_BYTES);
+
+    // Upper case is accepted, and the prefix is compared case-insensitively.
+    assert_eq!(
+        BECH32
+            .to_ascii_uppercase()
+            .parse::<Bech32Array<Abcdef, 20>>()
+            .unwrap(),
+        key,
+    );
+
+    let key = Bech32mArray::<Abcdef, 20>::new(BECH32M_BYTES);
+    assert_eq!(key.to_string(), BECH32M);
+    assert_eq!(BECH32M.parse::<Bech32mArray<Abcdef, 20>>().unwrap(), key);
+
+    // Empty data, from the BIP test vectors.
+    assert!("a12uel5l".parse::<Bech32Vec<A>>().unwrap().is_empty());
+    assert!("a1lqfn3a".parse::<Bech32mVec<A>>().unwrap().is_empty());
+    assert_eq!(Bech32mVec::<A>::default().to_string(), "a1lqfn3a");
+}
+
+#[test]
+fn bech32_too_long() {
+    // 2 symbols for "a1", 6 for the checksum, and 1015 for the data, which
+    // fit 634 bytes.
+    assert_eq!(
+        serde_human_bytes::encoding::Bech32::<A>::check_len(635),
+        Err(DecodeError::TooLong {
+            max: 634,
+            actual: 635,
+        }),
+    );
+
+    let longest = Bech32Vec::<A>::from(vec![0; 634]);
+    let encoded = longest.to_string();
+    assert_eq!(encoded.len(), 1023);
+    assert_eq!(encoded.parse::<Bech32Vec<A>>(), Ok(longest));
+
+    let error = serde_json::to_string(&Bech32Vec::<A>::from(vec![0; 635])).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 635, expected at most 634 bytes"
+    );
+    let error = serde_json::to_string(&Bech32mArray::<Abcdef, 700>::new([0; 700])).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 700, expected at most 631 bytes"
+    );
+
+    // `Display` and `Debug` fall back to hex rather than failing.
+    let too_long = Bech32Vec::<A>::from(vec![0; 635]);
+    let expected = format!("<invalid length 635: {}>", "00".repeat(635));
+    assert_eq!(too_long.to_string(), expected);
+    assert_eq!(format!("{too_long:?}"), format!("Bech32Vec({expected})"));
+}
+
+#[test]
+fn bech32_decode_errors() {
+    // A different prefix.
+    assert_eq!(
+        "a12uel5l".parse::<Bech32Vec<Abcdef>>(),
+        Err(DecodeError::InvalidPrefix {
+            expected: "abcdef",
+            found: FoundPrefix::new("a"),
+        }),
+    );
+    assert_eq!(
+        BECH32.parse::<Bech32Vec<A>>(),
+        Err(DecodeError::InvalidPrefix {
+            expected: "a",
+            found: FoundPrefix::new("abcdef"),
+        }),
+    );
+    assert_eq!(
+        "abcdef".parse::<Bech32Vec<A>>(),
+        Err(DecodeError::InvalidPrefix {
+            expected: "a",
+            found: FoundPrefix::new("abcdef"),
+        }),
+    );
+    let json = format!(r#""{BECH32}""#);
+    let error = serde_json::from_str::<Bech32Vec<A>>(&json).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        r#"invalid prefix "abcdef", expected "a" at line 1 column 47"#,
+    );
+
+    // A corrupted checksum, or a checksum of the other kind.
+    assert_eq!(
+        "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxx".parse::<Bech32Vec<Abcdef>>(),
+        Err(DecodeError::InvalidChecksum),
+    );
+    assert_eq!(
+        BECH32M.parse::<Bech32Vec<Abcdef>>(),
+        Err(DecodeError::InvalidChecksum),
+    );
+    assert_eq!(
+        BECH32.parse::<Bech32mVec<Abcdef>>(),
+        Err(DecodeError::InvalidChecksum),
+    );
+
+    // Characters outside the alphabet, and mixed case.
+    assert_eq!(
+        "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxb".parse::<Bech32Vec<Abcdef>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'b',
+            index: 44,
+        }),
+    );
+    assert_eq!(
+        "abcdef1Qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw".parse::<Bech32Vec<Abcdef>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'Q',
+            index: 7,
+        }),
+    );
+
+    // Too short to contain a checksum.
+    assert_eq!(
+        "a1qqq".parse::<Bech32Vec<A>>(),
+        Err(DecodeError::InvalidEncodedLength { length: 5 }),
+    );
+
+    // The wrong number of bytes for an array.
+    let error =
+        serde_json::from_str::<Bech32Array<Abcdef, 32>>(&format!(r#""{BECH32}""#)).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 20, expected a byte array [u8; 32] at line 1 column 47",
+    );
+}
@@ -0,0 +1,185 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{
+    DecodeError, HexArray, HumanBytes,
+    encoding::{Encoding, Hex, StringSchema},
+};
+
+/// A custom encoding that writes each byte as 8 binary digits.
+enum Binary {}
+
+impl Encoding for Binary {
+    const NAME: &'static str = "Binary";
+    const EXPECTING: &'static str = "a binary string";
+
+    fn encode<W>(bytes: &[u8], out: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        for byte in bytes {
+            write!(out, "{byte:08b}")?;
+        }
+        Ok(())
+    }
+
+    fn decoded_len(data: &str) -> Result<usize, DecodeError> {
+        if data.len() % 8 != 0 {
+            return Err(DecodeError::InvalidEncodedLength { length: data.len() });
+        }
+        Ok(data.len() / 8)
+    }
+
+    fn decode_to_slice(data: &str, out: &mut [u8]) -> Result<(), DecodeError> {
+        for (index, character) in data.char_indices() {
+            let bit = match character {
+                '0' => 0,
+                '1' => 1,
+                _ => return Err(DecodeError::InvalidCharacter { character, index }),
+            };
+            out[index / 8] = (out[index / 8] << 1) | bit;
+        }
+        Ok(())
+    }
+
+    fn schema(len: Option<usize>) -> StringSchema {
+        let mut schema = StringSchema::default();
+        schema.min_length = len.map(|len| len * 8);
+        schema.max_length = len.map(|len| len * 8);
+        schema.pattern = Some("^([01]{8})*$".into());
+        schema
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithBinary {
+    array: HumanBytes<Binary, [u8; 2]>,
+    vec: HumanBytes<Binary, Vec<u8>>,
+    #[serde(with = "HumanBytes::<Binary, Vec<u8>>")]
+    attr: Vec<u8>,
+}
+
+static AS_JSON: &str = r#"{"array":"0000000111111111","vec":"10100101","attr":""}"#;
+
+fn fixture() -> WithBinary {
+    WithBinary {
+        array: HumanBytes::new([0x01, 0xff]),
+        vec: vec![0xa5].into(),
+        attr: Vec::new(),
+    }
+}
+
+#[test]
+fn custom_encoding_serde() {
+    let fixture = fixture();
+
+    let json = serde_json::to_string(&fixture).expect("serializing as JSON succeeded");
+    assert_eq!(json, AS_JSON);
+    let json_actual: WithBinary =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(json_actual, fixture);
+
+    // Non-human-readable formats use bytes, as with the built-in encodings.
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("writing to vec<u8> succeeded");
+    let cbor_actual: WithBinary =
+        ciborium::de::from_reader(&*cbor).expect("deserializing from CBOR succeeded");
+    assert_eq!(cbor_actual, fixture);
+}
+
+#[test]
+fn custom_encoding_errors() {
+    let error = serde_json::from_str::<WithBinary>(r#"{"array":"00000001","vec":"","attr":""}"#)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 1, expected a byte array [u8; 2] at line 1 column 19",
+    );
+
+    let error = serde_json::from_str::<WithBinary>(
+        r#"{"array":"0000000111111111","vec":"1010010","attr":""}"#,
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid encoded length 7 at line 1 column 43",
+    );
+
+    let error = serde_json::from_str::<WithBinary>(r#"{"array":1}"#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid type: integer `1`, expected a binary string 2 bytes long at line 1 column 10",
+    );
+}
+
+#[test]
+fn custom_encoding_fmt_and_parse() {
+    let array: HumanBytes<Binary, [u8; 2]> = "0000000111111111".parse().unwrap();
+    assert_eq!(array.into_inner(), [0x01, 0xff]);
+    assert_eq!(array.to_string(), "0000000111111111");
+    assert_eq!(format!("{array:?}"), "BinaryArray(0000000111111111)");
+
+    let vec = HumanBytes::<Binary, Vec<u8>>::try_from("10100101").unwrap();
+    assert_eq!(*vec, [0xa5]);
+    assert_eq!(format!("{vec:?}"), "BinaryVec(10100101)");
+
+    assert_eq!(
+        "00000002".parse::<HumanBytes<Binary, Vec<u8>>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '2',
+            index: 7,
+        }),
+    );
+    assert_eq!(
+        "00000001".parse::<HumanBytes<Binary, [u8; 2]>>(),
+        Err(DecodeError::InvalidLength {
+            expected: 2,
+            actual: 1,
+        }),
+    );
+}
+
+#[test]
+fn aliases_are_human_bytes() {
+    let array: HumanBytes<Hex, [u8; 2]> = HexArray::<2>::new([0xab, 0xcd]);
+    assert_eq!(array.to_string(), "abcd");
+    assert_eq!(format!("{array:?}"), "HexArray(abcd)");
+}
+
+#[cfg(feature = "schemars08")]
+#[test]
+fn custom_encoding_schema() {
+    use schemars08::{JsonSchema, schema_for};
+
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars08")]
+    #[expect(dead_code)]
+    struct WithBinarySchema {
+        array: HumanBytes<Binary, [u8; 2]>,
+        vec: HumanBytes<Binary, Vec<u8>>,
+    }
+
+    let schema = serde_json::to_value(schema_for!(WithBinarySchema)).unwrap();
+    assert_eq!(
+        schema["properties"]["array"],
+        serde_json::json!({
+            "type": "string",
+            "minLength": 16,
+            "maxLength": 16,
+            "pattern": "^([01]{8})*$",
+        }),
+    );
+    assert_eq!(
+        schema["properties"]["vec"],
+        serde_json::json!({
+            "type": "string",
+            "pattern": "^([01]{8})*$",
+        }),
+    );
+    assert_eq!(
+        <HumanBytes<Binary, [u8; 2]>>::schema_name(),
+        "BinaryArray_2"
+    );
+}
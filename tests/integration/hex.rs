@@ -2,30 +2,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use hex_literal::hex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize)]
-#[cfg_attr(feature = "alloc", derive(serde::Serialize))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 struct MyStruct {
-    #[serde(deserialize_with = "serde_human_bytes::hex_array::deserialize")]
-    #[cfg_attr(
-        feature = "alloc",
-        serde(serialize_with = "serde_human_bytes::hex_array::serialize")
-    )]
+    #[serde(with = "serde_human_bytes::hex_array")]
     x: [u8; 16],
 }
 
 /// Test that `HexArray` works with `#[serde(with = "...")]`.
-#[cfg(feature = "alloc")]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 struct WithHexArrayAttr {
     #[serde(with = "serde_human_bytes::HexArray::<16>")]
     x: [u8; 16],
 }
 
 /// Test using `HexArray` directly as a field type.
-#[cfg(feature = "alloc")]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
 struct WithHexArrayDirect {
     x: serde_human_bytes::HexArray<16>,
 }
@@ -37,7 +30,6 @@ static FIXTURE: MyStruct = MyStruct {
 static AS_JSON: &str = r#"{"x":"0123456789abcdef0123456789abcdef"}"#;
 static AS_CBOR: [u8; 20] = hex!("a16178500123456789abcdef0123456789abcdef");
 
-#[cfg(feature = "alloc")]
 #[test]
 fn hex_serialize() {
     assert_eq!(
@@ -62,7 +54,6 @@ fn hex_deserialize() {
     assert_eq!(FIXTURE, cbor_actual, "deserializing from CBOR succeeded");
 }
 
-#[cfg(feature = "alloc")]
 #[test]
 fn hex_array_with_attr() {
     let fixture = WithHexArrayAttr {
@@ -76,7 +67,6 @@ fn hex_array_with_attr() {
     assert_eq!(fixture, roundtrip);
 }
 
-#[cfg(feature = "alloc")]
 #[test]
 fn hex_array_direct() {
     let fixture = WithHexArrayDirect {
@@ -89,3 +79,450 @@ fn hex_array_direct() {
     let roundtrip: WithHexArrayDirect = serde_json::from_str(&json).expect("deserialized");
     assert_eq!(fixture, roundtrip);
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct Upper {
+    #[serde(
+        serialize_with = "serde_human_bytes::hex_array::serialize_upper",
+        deserialize_with = "serde_human_bytes::hex_array::deserialize"
+    )]
+    x: [u8; 16],
+}
+
+#[test]
+fn hex_serialize_upper() {
+    let fixture = Upper { x: FIXTURE.x };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, r#"{"x":"0123456789ABCDEF0123456789ABCDEF"}"#);
+
+    let roundtrip: Upper = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+}
+
+#[test]
+fn hex_array_fmt() {
+    // Longer than the internal buffer used for formatting, to exercise
+    // chunking.
+    let mut bytes = [0u8; 70];
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    let expected: String = bytes.iter().map(|b| format!("{b:02x}")).collect();
+
+    let array = serde_human_bytes::HexArray::new(bytes);
+    assert_eq!(array.to_string(), expected);
+    assert_eq!(format!("{array:?}"), format!("HexArray({expected})"));
+    assert_eq!(
+        serde_json::to_string(&array).expect("serialized"),
+        format!("\"{expected}\""),
+    );
+
+    // Width, fill, alignment and precision work as they do for strings.
+    assert_eq!(format!("{array:>150}"), format!("{expected:>150}"));
+    assert_eq!(format!("{array:*^145}"), format!("{expected:*^145}"));
+    assert_eq!(format!("{array:<10.70}"), format!("{expected:<10.70}"));
+    let short = serde_human_bytes::HexArray::new([0xab, 0xcd]);
+    assert_eq!(format!("[{short:>6}]"), "[  abcd]");
+    assert_eq!(format!("[{short:-<6.2}]"), "[ab----]");
+}
+
+/// The same layout as `MyStruct`, but with serde's default representation for
+/// `[u8; N]`: a sequence of integers.
+#[derive(Serialize)]
+struct Plain<T> {
+    x: T,
+}
+
+#[test]
+fn hex_deserialize_seq() {
+    let mut cbor: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&Plain { x: FIXTURE.x }, &mut cbor).expect("serialized");
+    // Sanity check: the CBOR should be an array, not a byte string.
+    assert_ne!(cbor, AS_CBOR);
+
+    let actual: MyStruct = ciborium::de::from_reader(&cbor[..]).expect("deserialized");
+    assert_eq!(actual, FIXTURE);
+
+    let actual: WithHexArrayDirect = ciborium::de::from_reader(&cbor[..]).expect("deserialized");
+    assert_eq!(actual.x.into_inner(), FIXTURE.x);
+}
+
+#[test]
+fn hex_deserialize_seq_wrong_length() {
+    let mut cbor: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&Plain { x: [0u8; 15] }, &mut cbor).expect("serialized");
+    let err = ciborium::de::from_reader::<MyStruct, _>(&cbor[..]).expect_err("too short");
+    assert!(
+        err.to_string()
+            .contains("invalid length 15, expected a byte array [u8; 16]"),
+        "unexpected error: {err}",
+    );
+
+    let mut cbor: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&Plain { x: [0u8; 18] }, &mut cbor).expect("serialized");
+    let err = ciborium::de::from_reader::<MyStruct, _>(&cbor[..]).expect_err("too long");
+    assert!(
+        err.to_string()
+            .contains("invalid length 18, expected a byte array [u8; 16]"),
+        "unexpected error: {err}",
+    );
+}
+
+/// postcard isn't self-describing, so it can't tell a byte string from a
+/// tuple: `HexArray` round-trips through its own length-prefixed bytes, but
+/// can't read the bare bytes that serde writes for `[u8; N]`.
+#[test]
+fn hex_postcard() {
+    let bytes = postcard::to_allocvec(&FIXTURE).expect("serialized");
+    assert_eq!(bytes[0], 16, "length prefix");
+    assert_eq!(bytes[1..], FIXTURE.x);
+    let actual: MyStruct = postcard::from_bytes(&bytes).expect("deserialized");
+    assert_eq!(actual, FIXTURE);
+
+    let plain = postcard::to_allocvec(&Plain { x: FIXTURE.x }).expect("serialized");
+    assert_eq!(plain, FIXTURE.x, "no length prefix");
+    postcard::from_bytes::<MyStruct>(&plain).expect_err("plain arrays aren't readable");
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithTuple {
+    #[serde(with = "serde_human_bytes::hex_array::tuple")]
+    x: [u8; 16],
+}
+
+/// `hex_array::tuple` is byte-for-byte compatible with plain `[u8; N]` in
+/// binary formats, including ones that aren't self-describing.
+#[test]
+fn hex_tuple() {
+    let fixture = WithTuple { x: FIXTURE.x };
+    assert_eq!(
+        serde_json::to_string(&fixture).expect("serialized"),
+        AS_JSON
+    );
+    let actual: WithTuple = serde_json::from_str(AS_JSON).expect("deserialized");
+    assert_eq!(actual, fixture);
+
+    let plain = postcard::to_allocvec(&Plain { x: FIXTURE.x }).expect("serialized");
+    assert_eq!(postcard::to_allocvec(&fixture).expect("serialized"), plain);
+    let actual: WithTuple = postcard::from_bytes(&plain).expect("deserialized");
+    assert_eq!(actual, fixture);
+
+    let mut plain = Vec::new();
+    ciborium::ser::into_writer(&Plain { x: FIXTURE.x }, &mut plain).expect("serialized");
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("serialized");
+    assert_eq!(cbor, plain);
+    let actual: WithTuple = ciborium::de::from_reader(&plain[..]).expect("deserialized");
+    assert_eq!(actual, fixture);
+    let actual: WithTuple = ciborium::de::from_reader(&AS_CBOR[..]).expect("deserialized");
+    assert_eq!(actual, fixture);
+
+    let mut short = Vec::new();
+    ciborium::ser::into_writer(&Plain { x: [0u8; 15] }, &mut short).expect("serialized");
+    ciborium::de::from_reader::<WithTuple, _>(&short[..]).expect_err("too short");
+}
+
+#[test]
+fn hex_array_from_str() {
+    use serde_human_bytes::{DecodeError, HexArray};
+
+    let expected = HexArray::new(FIXTURE.x);
+    let parsed: HexArray<16> = "0123456789abcdef0123456789abcdef".parse().expect("parsed");
+    assert_eq!(parsed, expected);
+    assert_eq!(expected.to_string().parse::<HexArray<16>>(), Ok(expected));
+    assert_eq!(
+        HexArray::<16>::try_from("0123456789ABCDEF0123456789ABCDEF"),
+        Ok(expected),
+    );
+
+    let err = "0123456789abcdef0123456789abcdeg"
+        .parse::<HexArray<16>>()
+        .expect_err("invalid character");
+    assert_eq!(
+        err,
+        DecodeError::InvalidCharacter {
+            character: 'g',
+            index: 31,
+        }
+    );
+    assert_eq!(err.to_string(), "invalid character 'g' at index 31");
+
+    // Non-ASCII characters are reported as written.
+    let err = "0123456789abcdef0123456789abcdé"
+        .parse::<HexArray<16>>()
+        .expect_err("invalid character");
+    assert_eq!(
+        err,
+        DecodeError::InvalidCharacter {
+            character: 'é',
+            index: 30,
+        }
+    );
+
+    let err = "0123".parse::<HexArray<16>>().expect_err("wrong length");
+    assert_eq!(
+        err,
+        DecodeError::InvalidLength {
+            expected: 16,
+            actual: 2,
+        }
+    );
+    assert_eq!(err.to_string(), "invalid length 2, expected 16 bytes");
+
+    let err = "012".parse::<HexArray<16>>().expect_err("odd length");
+    assert_eq!(err, DecodeError::OddLength);
+}
+
+#[test]
+fn hex_array_try_from_slice() {
+    use serde_human_bytes::{DecodeError, HexArray};
+
+    assert_eq!(
+        HexArray::<16>::try_from(&FIXTURE.x[..]),
+        Ok(HexArray::new(FIXTURE.x)),
+    );
+    assert_eq!(
+        HexArray::<16>::try_from(&FIXTURE.x[..15]),
+        Err(DecodeError::InvalidLength {
+            expected: 16,
+            actual: 15,
+        }),
+    );
+}
+
+#[test]
+fn hex_deserialize_errors() {
+    // Wrong lengths are reported the same way as in binary formats.
+    let err = serde_json::from_str::<MyStruct>(r#"{"x":"0123456789abcdef0123456789abcd"}"#)
+        .expect_err("too short");
+    assert_eq!(
+        err.to_string(),
+        "invalid length 15, expected a byte array [u8; 16] at line 1 column 37",
+    );
+
+    let err = serde_json::from_str::<MyStruct>(r#"{"x":"0123456789abcdef0123456789abcdeg"}"#)
+        .expect_err("invalid character");
+    assert_eq!(
+        err.to_string(),
+        "invalid character 'g' at index 31 at line 1 column 39",
+    );
+
+    let err = serde_json::from_str::<MyStruct>(r#"{"x":"012"}"#).expect_err("odd length");
+    assert_eq!(
+        err.to_string(),
+        "odd number of hex digits at line 1 column 10"
+    );
+}
+
+#[test]
+fn hex_array_map_key() {
+    use serde_human_bytes::HexArray;
+    use std::collections::{BTreeMap, HashMap};
+
+    let a = HexArray::new(FIXTURE.x);
+    let b = HexArray::new(hex!("ffeeddccbbaa99887766554433221100"));
+    let map: BTreeMap<HexArray<16>, u32> = [(b, 2), (a, 1)].into_iter().collect();
+
+    // Keys are ordered by their bytes.
+    assert!(a < b);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), [a, b]);
+
+    // Lookups work with raw arrays and slices.
+    assert_eq!(map.get(&FIXTURE.x), Some(&1));
+    assert_eq!(map.get(&FIXTURE.x[..]), Some(&1));
+    let hash_map: HashMap<HexArray<16>, u32> = map.clone().into_iter().collect();
+    assert_eq!(hash_map.get(&FIXTURE.x[..]), Some(&1));
+
+    // JSON only supports string keys, which are serialized as hex.
+    let json = serde_json::to_string(&map).expect("serialized JSON");
+    assert_eq!(
+        json,
+        r#"{"0123456789abcdef0123456789abcdef":1,"ffeeddccbbaa99887766554433221100":2}"#,
+    );
+    let actual: BTreeMap<HexArray<16>, u32> = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(actual, map);
+    let value = serde_json::to_value(&map).expect("serialized JSON value");
+    assert_eq!(value.to_string(), json);
+    let actual: BTreeMap<HexArray<16>, u32> = serde_json::from_value(value).expect("deserialized");
+    assert_eq!(actual, map);
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&map, &mut cbor).expect("serialized CBOR");
+    let actual: BTreeMap<HexArray<16>, u32> =
+        ciborium::de::from_reader(&cbor[..]).expect("deserialized");
+    assert_eq!(actual, map);
+}
+
+#[test]
+fn hex_array_const() {
+    use serde_human_bytes::{HexArray, hex_array};
+
+    const FROM_FN: HexArray<16> = HexArray::from_hex_const("0123456789abcdef0123456789ABCDEF");
+    const FROM_MACRO: HexArray<16> = hex_array!("0123456789abcdef0123456789ABCDEF");
+    assert_eq!(FROM_FN.into_inner(), FIXTURE.x);
+    assert_eq!(FROM_MACRO, FROM_FN);
+
+    // The length is inferred without a type annotation.
+    let inferred = hex_array!("0123");
+    assert_eq!(inferred.into_inner(), [0x01, 0x23]);
+    let empty = hex_array!("");
+    assert_eq!(empty, HexArray::<0>::default());
+}
+
+#[test]
+#[should_panic = "invalid hex digit"]
+fn hex_array_const_invalid_digit() {
+    let _ = serde_human_bytes::HexArray::<2>::from_hex_const("01g3");
+}
+
+#[test]
+#[should_panic = "hex string has the wrong length"]
+fn hex_array_const_wrong_length() {
+    let _ = serde_human_bytes::HexArray::<2>::from_hex_const("012");
+}
+
+#[test]
+fn prefixed_hex_array() {
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+    struct Prefixed {
+        #[serde(with = "serde_human_bytes::hex_array::prefixed")]
+        address: [u8; 4],
+        hash: serde_human_bytes::PrefixedHexArray<4>,
+    }
+
+    let value = Prefixed {
+        address: hex!("deadbeef"),
+        hash: serde_human_bytes::PrefixedHexArray::new(hex!("0123abcd")),
+    };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"address":"0xdeadbeef","hash":"0x0123abcd"}"#);
+    assert_eq!(serde_json::from_str::<Prefixed>(&json).unwrap(), value);
+
+    // The prefix is optional when deserializing, and may be upper case.
+    let json = r#"{"address":"DEADBEEF","hash":"0X0123ABCD"}"#;
+    assert_eq!(serde_json::from_str::<Prefixed>(json).unwrap(), value);
+
+    assert_eq!(value.hash.to_string(), "0x0123abcd");
+    assert_eq!(format!("{:?}", value.hash), "PrefixedHexArray(0x0123abcd)");
+
+    // Errors report indexes in the input, including the prefix.
+    assert_eq!(
+        "0x0123abcg".parse::<serde_human_bytes::PrefixedHexArray<4>>(),
+        Err(serde_human_bytes::DecodeError::InvalidCharacter {
+            character: 'g',
+            index: 9,
+        }),
+    );
+    // Lengths count bytes, which the prefix doesn't add to.
+    assert_eq!(
+        "0X0123".parse::<serde_human_bytes::PrefixedHexArray<4>>(),
+        Err(serde_human_bytes::DecodeError::InvalidLength {
+            expected: 4,
+            actual: 2,
+        }),
+    );
+    assert_eq!(
+        "0X0123ABCD"
+            .parse::<serde_human_bytes::PrefixedHexArray<4>>()
+            .unwrap(),
+        value.hash,
+    );
+    assert_eq!(
+        "0x".parse::<serde_human_bytes::PrefixedHexArray<0>>(),
+        Ok(serde_human_bytes::PrefixedHexArray::new([])),
+    );
+    let error =
+        serde_json::from_str::<serde_human_bytes::PrefixedHexArray<4>>(r#""0x0123""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 2, expected a byte array [u8; 4] at line 1 column 8",
+    );
+}
+
+#[test]
+fn hex_upper_array() {
+    use serde_human_bytes::HexUpperArray;
+
+    let array = HexUpperArray::new(hex!("deadbeef"));
+    assert_eq!(serde_json::to_string(&array).unwrap(), r#""DEADBEEF""#);
+    assert_eq!(array.to_string(), "DEADBEEF");
+    assert_eq!(format!("{array:?}"), "HexUpperArray(DEADBEEF)");
+
+    // Either case is accepted.
+    assert_eq!("DeadBeef".parse::<HexUpperArray<4>>(), Ok(array));
+    assert_eq!(
+        serde_json::from_str::<HexUpperArray<4>>(r#""deadbeef""#).unwrap(),
+        array,
+    );
+}
+
+#[test]
+fn hex_strict_case() {
+    use serde_human_bytes::{DecodeError, HexStrictArray, HexUpperStrictArray};
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+    struct Strict {
+        #[serde(
+            serialize_with = "serde_human_bytes::hex_array::serialize",
+            deserialize_with = "serde_human_bytes::hex_array::deserialize_strict"
+        )]
+        lower: [u8; 2],
+        #[serde(
+            serialize_with = "serde_human_bytes::hex_array::serialize_upper",
+            deserialize_with = "serde_human_bytes::hex_array::deserialize_upper_strict"
+        )]
+        upper: [u8; 2],
+    }
+
+    let value = Strict {
+        lower: hex!("abcd"),
+        upper: hex!("abcd"),
+    };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"lower":"abcd","upper":"ABCD"}"#);
+    assert_eq!(serde_json::from_str::<Strict>(&json).unwrap(), value);
+
+    let error = serde_json::from_str::<Strict>(r#"{"lower":"abCd","upper":"ABCD"}"#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid character 'C' at index 2 at line 1 column 15",
+    );
+    let error = serde_json::from_str::<Strict>(r#"{"lower":"abcd","upper":"aBCD"}"#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid character 'a' at index 0 at line 1 column 30",
+    );
+
+    // The same checks apply to the types.
+    assert_eq!(
+        "ABCD".parse::<HexStrictArray<2>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'A',
+            index: 0,
+        }),
+    );
+    assert_eq!(
+        "ABCd".parse::<HexUpperStrictArray<2>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'd',
+            index: 3,
+        }),
+    );
+    let upper = HexUpperStrictArray::new(hex!("abcd"));
+    assert_eq!(upper.to_string(), "ABCD");
+    assert_eq!("ABCD".parse(), Ok(upper));
+    assert_eq!(
+        "abcd".parse::<HexStrictArray<2>>(),
+        Ok(HexStrictArray::new(hex!("abcd"))),
+    );
+
+    // Other invalid characters are reported as usual.
+    assert_eq!(
+        "abxd".parse::<HexStrictArray<2>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'x',
+            index: 2,
+        }),
+    );
+}
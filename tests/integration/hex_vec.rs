@@ -0,0 +1,195 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::HexVec;
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    #[serde(with = "serde_human_bytes::hex_vec")]
+    data: Vec<u8>,
+}
+
+/// Test that `HexVec` works with `#[serde(with = "...")]`.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithHexVecAttr {
+    #[serde(with = "HexVec")]
+    data: Vec<u8>,
+}
+
+/// Test using `HexVec` directly as a field type.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct WithHexVecDirect {
+    data: HexVec,
+}
+
+static FIXTURE: &[u8] = &hex!("0123456789abcdef0123456789abcdef");
+
+static AS_JSON: &str = r#"{"data":"0123456789abcdef0123456789abcdef"}"#;
+
+// CBOR: map with one key "data" and a byte string value.
+static AS_CBOR: [u8; 23] = hex!("a1646461746150 0123456789abcdef0123456789abcdef");
+
+fn fixture() -> MyStruct {
+    MyStruct {
+        data: FIXTURE.to_vec(),
+    }
+}
+
+#[test]
+fn hex_vec_serialize() {
+    let fixture = fixture();
+
+    assert_eq!(
+        serde_json::to_string(&fixture).expect("serializing as JSON succeeded"),
+        AS_JSON,
+        "JSON matched",
+    );
+
+    let mut cbor_actual: Vec<u8> = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor_actual).expect("writing to vec<u8> succeeded");
+
+    assert_eq!(cbor_actual, AS_CBOR, "CBOR matched");
+}
+
+#[test]
+fn hex_vec_deserialize() {
+    let fixture = fixture();
+
+    let json_actual: MyStruct =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing from JSON matched");
+
+    let cbor_actual: MyStruct =
+        ciborium::de::from_reader(&AS_CBOR[..]).expect("deserializing from CBOR succeeded");
+    assert_eq!(fixture, cbor_actual, "deserializing from CBOR succeeded");
+}
+
+#[test]
+fn hex_vec_deserialize_upper_and_empty() {
+    let upper: MyStruct = serde_json::from_str(r#"{"data":"0123456789ABCDEF0123456789ABCDEF"}"#)
+        .expect("deserializing upper-case hex succeeded");
+    assert_eq!(upper, fixture());
+
+    let empty: MyStruct =
+        serde_json::from_str(r#"{"data":""}"#).expect("deserializing empty hex succeeded");
+    assert_eq!(empty.data, Vec::<u8>::new());
+
+    serde_json::from_str::<MyStruct>(r#"{"data":"012"}"#).expect_err("odd-length hex rejected");
+}
+
+#[test]
+fn hex_vec_with_attr() {
+    let fixture = WithHexVecAttr {
+        data: FIXTURE.to_vec(),
+    };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, AS_JSON);
+
+    let roundtrip: WithHexVecAttr = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+}
+
+#[test]
+fn hex_vec_direct() {
+    let fixture = WithHexVecDirect {
+        data: HexVec::new(FIXTURE.to_vec()),
+    };
+
+    let json = serde_json::to_string(&fixture).expect("serialized");
+    assert_eq!(json, AS_JSON);
+
+    let roundtrip: WithHexVecDirect = serde_json::from_str(&json).expect("deserialized");
+    assert_eq!(fixture, roundtrip);
+
+    assert_eq!(
+        format!("{:?}", fixture.data),
+        "HexVec(0123456789abcdef0123456789abcdef)"
+    );
+    assert_eq!(fixture.data.to_string(), "0123456789abcdef0123456789abcdef");
+}
+
+#[test]
+fn hex_vec_from_str() {
+    use serde_human_bytes::DecodeError;
+
+    let expected = HexVec::new(FIXTURE.to_vec());
+    assert_eq!(
+        "0123456789abcdef0123456789abcdef".parse::<HexVec>(),
+        Ok(expected.clone()),
+    );
+    assert_eq!(expected.to_string().parse::<HexVec>(), Ok(expected.clone()));
+    assert_eq!(HexVec::try_from(""), Ok(HexVec::default()));
+    assert_eq!(HexVec::from(FIXTURE), expected);
+
+    assert_eq!(
+        "01x3".parse::<HexVec>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'x',
+            index: 2,
+        }),
+    );
+    assert_eq!("012".parse::<HexVec>(), Err(DecodeError::OddLength));
+}
+
+#[test]
+fn hex_vec_deserialize_errors() {
+    let err = serde_json::from_str::<MyStruct>(r#"{"data":"01x3"}"#).expect_err("invalid");
+    assert_eq!(
+        err.to_string(),
+        "invalid character 'x' at index 2 at line 1 column 14",
+    );
+}
+
+#[test]
+fn prefixed_hex_vec() {
+    #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+    struct Prefixed {
+        #[serde(with = "serde_human_bytes::hex_vec::prefixed")]
+        input: Vec<u8>,
+        data: serde_human_bytes::PrefixedHexVec,
+    }
+
+    let value = Prefixed {
+        input: hex!("a9059cbb").to_vec(),
+        data: serde_human_bytes::PrefixedHexVec::default(),
+    };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"input":"0xa9059cbb","data":"0x"}"#);
+    assert_eq!(serde_json::from_str::<Prefixed>(&json).unwrap(), value);
+
+    // The prefix is optional when deserializing.
+    let json = r#"{"input":"A9059CBB","data":""}"#;
+    assert_eq!(serde_json::from_str::<Prefixed>(json).unwrap(), value);
+
+    // Non-human-readable formats use raw bytes, without the prefix.
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&value, &mut cbor).unwrap();
+    let cbor_actual: Prefixed = ciborium::de::from_reader(&*cbor).unwrap();
+    assert_eq!(cbor_actual, value);
+
+    assert_eq!(
+        "0xabc".parse::<serde_human_bytes::PrefixedHexVec>(),
+        Err(serde_human_bytes::DecodeError::OddLength),
+    );
+
+    // An upper-case prefix, and a prefix alone.
+    assert_eq!(
+        *"0XA9059CBB"
+            .parse::<serde_human_bytes::PrefixedHexVec>()
+            .unwrap(),
+        value.input,
+    );
+    assert!(
+        "0x".parse::<serde_human_bytes::PrefixedHexVec>()
+            .unwrap()
+            .is_empty()
+    );
+    assert!(
+        "0X".parse::<serde_human_bytes::PrefixedHexVec>()
+            .unwrap()
+            .is_empty()
+    );
+}
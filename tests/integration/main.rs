@@ -1,8 +1,33 @@
 // Copyright (c) The serde_human_bytes Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "alloc")]
+mod base32;
+#[cfg(feature = "base58")]
+mod base58;
 #[cfg(feature = "alloc")]
 mod base64;
+#[cfg(feature = "alloc")]
+mod base64_array;
+#[cfg(feature = "alloc")]
+mod base64_bounded;
+#[cfg(feature = "alloc")]
+mod base64_cow;
+#[cfg(feature = "alloc")]
+mod base85;
+#[cfg(all(feature = "bech32", feature = "alloc"))]
+mod bech32;
+#[cfg(feature = "alloc")]
+mod encoding;
 mod hex;
+#[cfg(feature = "alloc")]
+mod hex_vec;
 #[cfg(feature = "schemars08")]
 mod schemars;
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+mod secret;
+mod separated_hex;
+#[cfg(all(feature = "subtle", feature = "alloc"))]
+mod subtle;
+#[cfg(feature = "utoipa")]
+mod utoipa;
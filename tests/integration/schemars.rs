@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use schemars08::{self as schemars, JsonSchema, schema_for};
-use serde_human_bytes::{Base64Vec, HexArray};
+use serde_human_bytes::{
+    Base32Array, Base32CrockfordArray, Base32HexNoPadVec, Base32Vec, Base64Array, Base64Cow,
+    Base64LenientVec, Base64NoPadVec, Base64UrlNoPadVec, Base64UrlVec, Base64Vec, BoundedBase64Vec,
+    HexArray, HexVec,
+};
 
 #[test]
 fn hex_array_schema() {
@@ -19,6 +23,222 @@ fn hex_array_schema() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn base64_array_schema() {
+    let schema = schema_for!(Base64Array<16>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base64Array_16",
+        "type": "string",
+        "format": "byte",
+        "contentEncoding": "base64",
+        "maxLength": 24,
+        "minLength": 24,
+        "pattern": "^[A-Za-z0-9+/]{22}==$"
+    });
+    assert_eq!(actual, expected);
+
+    let schema = schema_for!(Base64Array<32>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    assert_eq!(actual["maxLength"], 44);
+    assert_eq!(actual["pattern"], "^[A-Za-z0-9+/]{43}=$");
+
+    let schema = schema_for!(Base64Array<48>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    assert_eq!(actual["maxLength"], 64);
+    assert_eq!(actual["pattern"], "^[A-Za-z0-9+/]{64}$");
+}
+
+#[test]
+fn base32_array_schema() {
+    let schema = schema_for!(Base32Array<16>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base32Array_16",
+        "type": "string",
+        "contentEncoding": "base32",
+        "maxLength": 32,
+        "minLength": 32,
+        "pattern": "^[A-Za-z2-7]{26}======$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(Base32Array<20>)).expect("serialized");
+    assert_eq!(actual["maxLength"], 32);
+    assert_eq!(actual["pattern"], "^[A-Za-z2-7]{32}$");
+
+    let actual = serde_json::to_value(schema_for!(Base32CrockfordArray<16>)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base32CrockfordArray_16",
+        "type": "string",
+        "pattern": "^[0-9A-TV-Za-tv-z-]*$"
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn base32_vec_schemas() {
+    let actual = serde_json::to_value(schema_for!(Base32Vec)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base32Vec",
+        "type": "string",
+        "contentEncoding": "base32",
+        "pattern": "^([A-Za-z2-7]{8})*([A-Za-z2-7]{2}={6}|[A-Za-z2-7]{4}={4}|[A-Za-z2-7]{5}={3}|[A-Za-z2-7]{7}=)?$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(Base32HexNoPadVec)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base32HexNoPadVec",
+        "type": "string",
+        "pattern": "^([0-9A-Va-v]{8})*([0-9A-Va-v]{2}|[0-9A-Va-v]{4,5}|[0-9A-Va-v]{7})?$"
+    });
+    assert_eq!(actual, expected);
+}
+
+#[cfg(feature = "base58")]
+#[test]
+fn base58_schemas() {
+    let schema = schema_for!(serde_human_bytes::Base58CheckArray<21>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base58CheckArray_21",
+        "type": "string",
+        "pattern": "^[1-9A-HJ-NP-Za-km-z]*$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual =
+        serde_json::to_value(schema_for!(serde_human_bytes::Base58Vec)).expect("serialized");
+    assert_eq!(actual["title"], "Base58Vec");
+    assert_eq!(actual["pattern"], "^[1-9A-HJ-NP-Za-km-z]*$");
+}
+
+#[test]
+fn base85_schemas() {
+    let schema = schema_for!(serde_human_bytes::Z85Array<8>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Z85Array_8",
+        "type": "string",
+        "maxLength": 10,
+        "minLength": 10,
+        "pattern": r"^[0-9a-zA-Z.\-:+=^!/*?&<>()\[\]{}@%$#]{10}$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(serde_human_bytes::Z85Vec)).expect("serialized");
+    assert_eq!(
+        actual["pattern"],
+        r"^([0-9a-zA-Z.\-:+=^!/*?&<>()\[\]{}@%$#]{5})*$"
+    );
+
+    let actual =
+        serde_json::to_value(schema_for!(serde_human_bytes::Ascii85Array<7>)).expect("serialized");
+    assert_eq!(actual["maxLength"], 9);
+    assert_eq!(actual["pattern"], "^[!-uz]*$");
+}
+
+#[cfg(feature = "bech32")]
+#[test]
+fn bech32_schemas() {
+    use serde_human_bytes::{Bech32Array, Bech32mVec, encoding::Bech32Hrp};
+
+    enum Npub {}
+
+    impl Bech32Hrp for Npub {
+        const HRP: &'static str = "npub";
+    }
+
+    let schema = schema_for!(Bech32Array<Npub, 32>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Bech32Array_32_npub",
+        "type": "string",
+        "maxLength": 63,
+        "minLength": 63,
+        "pattern": "^npub1[02-9ac-hj-np-z]{58}$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(Bech32mVec<Npub>)).expect("serialized");
+    assert_eq!(actual["title"], "Bech32mVec_npub");
+    assert_eq!(actual["pattern"], "^npub1[02-9ac-hj-np-z]{6,}$");
+}
+
+#[test]
+fn hex_case_schemas() {
+    let actual =
+        serde_json::to_value(schema_for!(serde_human_bytes::HexUpperArray<4>)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "HexUpperArray_4",
+        "type": "string",
+        "maxLength": 8,
+        "minLength": 8,
+        "pattern": "^[0-9A-F]{8}$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(serde_human_bytes::HexStrictArray<4>))
+        .expect("serialized");
+    assert_eq!(actual["pattern"], "^[0-9a-f]{8}$");
+    let actual = serde_json::to_value(schema_for!(serde_human_bytes::HexUpperStrictArray<4>))
+        .expect("serialized");
+    assert_eq!(actual["pattern"], "^[0-9A-F]{8}$");
+}
+
+#[test]
+fn prefixed_hex_schemas() {
+    let schema = schema_for!(serde_human_bytes::PrefixedHexArray<20>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "PrefixedHexArray_20",
+        "type": "string",
+        "maxLength": 42,
+        "minLength": 42,
+        "pattern": "^0x[0-9a-fA-F]{40}$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual =
+        serde_json::to_value(schema_for!(serde_human_bytes::PrefixedHexVec)).expect("serialized");
+    assert_eq!(actual["title"], "PrefixedHexVec");
+    assert_eq!(actual["pattern"], "^0x([0-9a-fA-F]{2})*$");
+}
+
+#[test]
+fn separated_hex_schemas() {
+    let schema = schema_for!(serde_human_bytes::SeparatedHexArray<6>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "SeparatedHexArray_6_-3A_1_lower",
+        "type": "string",
+        "maxLength": 17,
+        "minLength": 17,
+        "pattern": "^([0-9a-fA-F]{2}:){5}[0-9a-fA-F]{2}$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(
+        serde_human_bytes::SeparatedHexArray<5, '.', 2>
+    ))
+    .expect("serialized");
+    assert_eq!(actual["title"], "SeparatedHexArray_5_-2E_2_lower");
+    assert_eq!(actual["maxLength"], 12);
+    assert_eq!(actual["pattern"], r"^([0-9a-fA-F]{4}\.){2}[0-9a-fA-F]{2}$");
+}
+
 #[test]
 fn base64_vec_schema() {
     let schema = schema_for!(Base64Vec);
@@ -33,6 +253,94 @@ fn base64_vec_schema() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn base64_vec_alphabet_schemas() {
+    let actual = serde_json::to_value(schema_for!(Base64NoPadVec)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base64NoPadVec",
+        "type": "string",
+        "contentEncoding": "base64",
+        "pattern": "^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2,3})?$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(Base64UrlVec)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base64UrlVec",
+        "type": "string",
+        "contentEncoding": "base64url",
+        "pattern": "^([A-Za-z0-9_-]{4})*([A-Za-z0-9_-]{2}==|[A-Za-z0-9_-]{3}=)?$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(Base64UrlNoPadVec)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base64UrlNoPadVec",
+        "type": "string",
+        "contentEncoding": "base64url",
+        "pattern": "^([A-Za-z0-9_-]{4})*([A-Za-z0-9_-]{2,3})?$"
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(Base64LenientVec)).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base64LenientVec",
+        "type": "string",
+        "contentEncoding": "base64",
+        "pattern": "^[A-Za-z0-9+/_-]*={0,2}$"
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn bounded_base64_vec_schema() {
+    let schema = schema_for!(BoundedBase64Vec<16>);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "BoundedBase64Vec_16",
+        "type": "string",
+        "format": "byte",
+        "contentEncoding": "base64",
+        "maxLength": 24
+    });
+    assert_eq!(actual, expected);
+
+    let actual = serde_json::to_value(schema_for!(BoundedBase64Vec<32>)).expect("serialized");
+    assert_eq!(actual["maxLength"], 44);
+}
+
+#[test]
+fn base64_cow_schema() {
+    let schema = schema_for!(Base64Cow);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "Base64Cow",
+        "type": "string",
+        "format": "byte",
+        "contentEncoding": "base64"
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn hex_vec_schema() {
+    let schema = schema_for!(HexVec);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "HexVec",
+        "type": "string",
+        "pattern": "^([0-9a-fA-F]{2})*$"
+    });
+    assert_eq!(actual, expected);
+}
+
 #[expect(dead_code)]
 #[derive(JsonSchema)]
 struct WithHexArrayAttr {
@@ -59,6 +367,19 @@ struct WithBase64VecDirect {
     data: Base64Vec,
 }
 
+#[expect(dead_code)]
+#[derive(JsonSchema)]
+struct WithHexVecAttr {
+    #[schemars(with = "HexVec")]
+    data: Vec<u8>,
+}
+
+#[expect(dead_code)]
+#[derive(JsonSchema)]
+struct WithHexVecDirect {
+    data: HexVec,
+}
+
 #[test]
 fn with_hex_array_attr() {
     let schema = schema_for!(WithHexArrayAttr);
@@ -140,3 +461,148 @@ fn with_base64_vec_direct() {
     });
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn with_hex_vec_attr() {
+    let schema = schema_for!(WithHexVecAttr);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "WithHexVecAttr",
+        "type": "object",
+        "required": ["data"],
+        "properties": {
+            "data": {
+                "type": "string",
+                "pattern": "^([0-9a-fA-F]{2})*$"
+            }
+        }
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn with_hex_vec_direct() {
+    let schema = schema_for!(WithHexVecDirect);
+    let actual = serde_json::to_value(&schema).expect("serialized");
+    let expected = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "WithHexVecDirect",
+        "type": "object",
+        "required": ["data"],
+        "properties": {
+            "data": {
+                "type": "string",
+                "pattern": "^([0-9a-fA-F]{2})*$"
+            }
+        }
+    });
+    assert_eq!(actual, expected);
+}
+
+/// Checks that schemars 1.x produces the same schemas as 0.8, when
+/// configured for the same JSON Schema draft.
+#[cfg(feature = "schemars1")]
+mod schemars1 {
+    use schemars1::{JsonSchema, generate::SchemaSettings};
+    use serde_human_bytes::{
+        Base32Array, Base32CrockfordArray, Base32HexNoPadVec, Base32Vec, Base64Array, Base64Cow,
+        Base64LenientVec, Base64NoPadVec, Base64UrlNoPadVec, Base64UrlVec, Base64Vec,
+        BoundedBase64Vec, HexArray, HexVec,
+    };
+
+    fn assert_same_schema<T08, T1>()
+    where
+        T08: schemars08::JsonSchema,
+        T1: JsonSchema,
+    {
+        let expected = serde_json::to_value(schemars08::schema_for!(T08)).expect("serialized");
+        let actual = SchemaSettings::draft07()
+            .into_generator()
+            .into_root_schema_for::<T1>();
+        let actual = serde_json::to_value(actual).expect("serialized");
+        assert_eq!(actual, expected);
+    }
+
+    fn assert_same_type_schema<T>()
+    where
+        T: schemars08::JsonSchema + JsonSchema,
+    {
+        assert_same_schema::<T, T>();
+    }
+
+    #[test]
+    fn type_schemas() {
+        assert_same_type_schema::<HexArray<16>>();
+        assert_same_type_schema::<HexVec>();
+        assert_same_type_schema::<Base64Array<16>>();
+        assert_same_type_schema::<Base64Array<32>>();
+        assert_same_type_schema::<Base64Array<48>>();
+        assert_same_type_schema::<Base64Vec>();
+        assert_same_type_schema::<Base64NoPadVec>();
+        assert_same_type_schema::<Base64UrlVec>();
+        assert_same_type_schema::<Base64UrlNoPadVec>();
+        assert_same_type_schema::<Base64LenientVec>();
+        assert_same_type_schema::<Base64Cow>();
+        assert_same_type_schema::<BoundedBase64Vec<16>>();
+        assert_same_type_schema::<Base32Array<16>>();
+        assert_same_type_schema::<Base32CrockfordArray<16>>();
+        assert_same_type_schema::<Base32Vec>();
+        assert_same_type_schema::<Base32HexNoPadVec>();
+    }
+
+    #[expect(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars1")]
+    struct WithHexArrayAttr {
+        #[schemars(with = "HexArray<16>")]
+        x: [u8; 16],
+    }
+
+    #[expect(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars1")]
+    struct WithHexArrayDirect {
+        x: HexArray<16>,
+    }
+
+    #[expect(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars1")]
+    struct WithBase64VecAttr {
+        #[schemars(with = "Base64Vec")]
+        data: Vec<u8>,
+    }
+
+    #[expect(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars1")]
+    struct WithBase64VecDirect {
+        data: Base64Vec,
+    }
+
+    #[expect(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars1")]
+    struct WithHexVecAttr {
+        #[schemars(with = "HexVec")]
+        data: Vec<u8>,
+    }
+
+    #[expect(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(crate = "schemars1")]
+    struct WithHexVecDirect {
+        data: HexVec,
+    }
+
+    #[test]
+    fn struct_schemas() {
+        assert_same_schema::<super::WithHexArrayAttr, WithHexArrayAttr>();
+        assert_same_schema::<super::WithHexArrayDirect, WithHexArrayDirect>();
+        assert_same_schema::<super::WithBase64VecAttr, WithBase64VecAttr>();
+        assert_same_schema::<super::WithBase64VecDirect, WithBase64VecDirect>();
+        assert_same_schema::<super::WithHexVecAttr, WithHexVecAttr>();
+        assert_same_schema::<super::WithHexVecDirect, WithHexVecDirect>();
+    }
+}
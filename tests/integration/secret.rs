@@ -0,0 +1,112 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{DecodeError, SecretBase64Vec, SecretHexArray};
+use zeroize::Zeroize;
+
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct Credentials {
+    key: SecretHexArray<16>,
+    token: SecretBase64Vec,
+}
+
+static KEY: [u8; 16] = hex!("0123456789abcdef0123456789abcdef");
+
+static AS_JSON: &str =
+    r#"{"key":"0123456789abcdef0123456789abcdef","token":"ASNFZ4mrze8BI0VniavN7w=="}"#;
+
+fn fixture() -> Credentials {
+    Credentials {
+        key: SecretHexArray::new(KEY),
+        token: SecretBase64Vec::new(KEY.to_vec()),
+    }
+}
+
+#[test]
+fn secret_roundtrip() {
+    let fixture = fixture();
+    assert_eq!(
+        serde_json::to_string(&fixture).expect("serialized"),
+        AS_JSON
+    );
+    let actual: Credentials = serde_json::from_str(AS_JSON).expect("deserialized");
+    assert_eq!(actual, fixture);
+
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("serialized");
+    let actual: Credentials = ciborium::de::from_reader(&cbor[..]).expect("deserialized");
+    assert_eq!(actual, fixture);
+    assert_eq!(actual.key.expose_secret(), &KEY);
+    assert_eq!(actual.token.expose_secret(), KEY);
+
+    // Deserialization follows the same rules as the non-secret types.
+    serde_json::from_str::<Credentials>(
+        r#"{"key":"0123456789abcdef0123456789abcd","token":"ASNFZ4mrze8BI0VniavN7w=="}"#,
+    )
+    .expect_err("wrong length");
+}
+
+#[test]
+fn secret_redacted() {
+    let fixture = fixture();
+    assert_eq!(
+        format!("{fixture:?}"),
+        "Credentials { key: SecretHexArray([REDACTED]), token: SecretBase64Vec([REDACTED]) }",
+    );
+    assert_eq!(fixture.key.to_string(), "[REDACTED]");
+    assert_eq!(fixture.token.to_string(), "[REDACTED]");
+}
+
+#[test]
+fn secret_eq() {
+    let mut other = KEY;
+    other[15] ^= 1;
+    assert_eq!(SecretHexArray::new(KEY), SecretHexArray::new(KEY));
+    assert_ne!(SecretHexArray::new(KEY), SecretHexArray::new(other));
+
+    assert_eq!(
+        SecretBase64Vec::new(KEY.to_vec()),
+        SecretBase64Vec::new(KEY.to_vec())
+    );
+    assert_ne!(
+        SecretBase64Vec::new(KEY.to_vec()),
+        SecretBase64Vec::new(other.to_vec())
+    );
+    assert_ne!(
+        SecretBase64Vec::new(KEY.to_vec()),
+        SecretBase64Vec::new(KEY[..15].to_vec())
+    );
+}
+
+#[test]
+fn secret_zeroize() {
+    let mut key = SecretHexArray::new(KEY);
+    key.zeroize();
+    assert_eq!(key.expose_secret(), &[0; 16]);
+
+    let mut token = SecretBase64Vec::new(KEY.to_vec());
+    token.zeroize();
+    assert!(token.expose_secret().is_empty());
+}
+
+#[test]
+fn secret_from_str() {
+    let key: SecretHexArray<16> = "0123456789abcdef0123456789abcdef".parse().expect("parsed");
+    assert_eq!(key.expose_secret(), &KEY);
+    assert_eq!(
+        SecretHexArray::<16>::try_from("0123"),
+        Err(DecodeError::InvalidLength {
+            expected: 16,
+            actual: 2,
+        }),
+    );
+
+    let token: SecretBase64Vec = "ASNFZ4mrze8BI0VniavN7w==".parse().expect("parsed");
+    assert_eq!(token.expose_secret(), KEY);
+    assert_eq!(
+        SecretBase64Vec::try_from("ASNFZ"),
+        Err(DecodeError::InvalidEncodedLength { length: 5 }),
+    );
+}
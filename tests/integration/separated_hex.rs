@@ -0,0 +1,107 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde::{Deserialize, Serialize};
+use serde_human_bytes::{DecodeError, SeparatedHexArray};
+
+static MAC: [u8; 6] = hex!("0a1b2c3d4e5f");
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+struct MyStruct {
+    mac: SeparatedHexArray<6>,
+    #[serde(with = "serde_human_bytes::separated_hex_array::dash")]
+    dashed: [u8; 6],
+    #[serde(with = "SeparatedHexArray::<8, '-', 2, true>")]
+    eui64: [u8; 8],
+}
+
+static AS_JSON: &str =
+    r#"{"mac":"0a:1b:2c:3d:4e:5f","dashed":"0a-1b-2c-3d-4e-5f","eui64":"0011-2233-4455-6677"}"#;
+
+fn fixture() -> MyStruct {
+    MyStruct {
+        mac: SeparatedHexArray::new(MAC),
+        dashed: MAC,
+        eui64: hex!("0011223344556677"),
+    }
+}
+
+#[test]
+fn separated_hex_serde() {
+    let fixture = fixture();
+
+    let json = serde_json::to_string(&fixture).expect("serializing as JSON succeeded");
+    assert_eq!(json, AS_JSON, "JSON matched");
+    let json_actual: MyStruct =
+        serde_json::from_str(AS_JSON).expect("deserializing from JSON succeeded");
+    assert_eq!(fixture, json_actual, "deserializing from JSON matched");
+
+    // Non-human-readable formats use raw bytes, without separators.
+    let mut cbor = Vec::new();
+    ciborium::ser::into_writer(&fixture, &mut cbor).expect("writing to vec<u8> succeeded");
+    assert!(
+        cbor.ends_with(&hex!("0011223344556677")),
+        "CBOR contains raw bytes"
+    );
+    let cbor_actual: MyStruct =
+        ciborium::de::from_reader(&*cbor).expect("deserializing from CBOR succeeded");
+    assert_eq!(fixture, cbor_actual, "deserializing from CBOR matched");
+}
+
+#[test]
+fn separated_hex_types() {
+    let mac = SeparatedHexArray::<6>::new(MAC);
+    assert_eq!(mac.to_string(), "0a:1b:2c:3d:4e:5f");
+    assert_eq!(format!("{mac:?}"), "SeparatedHexArray(0a:1b:2c:3d:4e:5f)");
+    // Either case is accepted.
+    assert_eq!("0A:1b:2C:3d:4E:5f".parse::<SeparatedHexArray<6>>(), Ok(mac));
+
+    let upper = SeparatedHexArray::<6, '-', 1, true>::new(MAC);
+    assert_eq!(upper.to_string(), "0A-1B-2C-3D-4E-5F");
+
+    // A partial final group.
+    let dotted = SeparatedHexArray::<5, '.', 2>::new(hex!("0a1b2c3d4e"));
+    assert_eq!(dotted.to_string(), "0a1b.2c3d.4e");
+    assert_eq!("0a1b.2c3d.4e".parse(), Ok(dotted));
+
+    assert_eq!(SeparatedHexArray::<0>::default().to_string(), "");
+    assert_eq!("".parse::<SeparatedHexArray<0>>(), Ok(Default::default()));
+}
+
+#[test]
+fn separated_hex_decode_errors() {
+    // Missing, misplaced or different separators.
+    assert_eq!(
+        "0a1b2c3d4e5f".parse::<SeparatedHexArray<6>>(),
+        Err(DecodeError::InvalidEncodedLength { length: 12 }),
+    );
+    assert_eq!(
+        "0a:1b:2c:3d:4e5:f".parse::<SeparatedHexArray<6>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '5',
+            index: 14,
+        }),
+    );
+    assert_eq!(
+        "0a-1b-2c-3d-4e-5f".parse::<SeparatedHexArray<6>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: '-',
+            index: 2,
+        }),
+    );
+    assert_eq!(
+        "0a:1b:2c:3d:4e:5g".parse::<SeparatedHexArray<6>>(),
+        Err(DecodeError::InvalidCharacter {
+            character: 'g',
+            index: 16,
+        }),
+    );
+
+    // The wrong number of bytes.
+    let error = serde_json::from_str::<SeparatedHexArray<6>>(r#""0a:1b:2c:3d:4e""#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid length 5, expected a byte array [u8; 6] at line 1 column 16",
+    );
+}
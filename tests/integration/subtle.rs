@@ -0,0 +1,48 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use hex_literal::hex;
+use serde_human_bytes::{Base64Vec, HexArray};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+static MAC: [u8; 16] = hex!("0123456789abcdef0123456789abcdef");
+
+fn other_mac() -> [u8; 16] {
+    let mut other = MAC;
+    other[15] ^= 1;
+    other
+}
+
+#[test]
+fn hex_array_ct_eq() {
+    let a = HexArray::new(MAC);
+    let b = HexArray::new(other_mac());
+
+    assert!(bool::from(a.ct_eq(&HexArray::new(MAC))));
+    assert!(!bool::from(a.ct_eq(&b)));
+    assert!(bool::from(ConstantTimeEq::ct_eq(&a, &a)));
+    assert!(!bool::from(ConstantTimeEq::ct_eq(&a, &b)));
+}
+
+#[test]
+fn hex_array_conditional_select() {
+    let a = HexArray::new(MAC);
+    let b = HexArray::new(other_mac());
+
+    assert_eq!(HexArray::conditional_select(&a, &b, Choice::from(0)), a);
+    assert_eq!(HexArray::conditional_select(&a, &b, Choice::from(1)), b);
+
+    let mut c = a;
+    c.conditional_assign(&b, Choice::from(1));
+    assert_eq!(c, b);
+}
+
+#[test]
+fn base64_vec_ct_eq() {
+    let a = Base64Vec::new(MAC.to_vec());
+
+    assert!(bool::from(a.ct_eq(&Base64Vec::new(MAC.to_vec()))));
+    assert!(!bool::from(a.ct_eq(&Base64Vec::new(other_mac().to_vec()))));
+    assert!(!bool::from(a.ct_eq(&Base64Vec::new(MAC[..15].to_vec()))));
+    assert!(bool::from(ConstantTimeEq::ct_eq(&a, &a)));
+}
@@ -0,0 +1,156 @@
+// Copyright (c) The serde_human_bytes Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_human_bytes::{
+    Base64Array, Base64Cow, Base64LenientVec, Base64NoPadVec, Base64UrlNoPadVec, Base64UrlVec,
+    Base64Vec, BoundedBase64Vec, HexArray, HexVec, SeparatedHexArray,
+};
+use utoipa::{OpenApi, PartialSchema, ToSchema};
+
+fn schema_json<T: PartialSchema>() -> serde_json::Value {
+    serde_json::to_value(T::schema()).expect("serialized")
+}
+
+#[test]
+fn hex_array_schema() {
+    assert_eq!(HexArray::<16>::name(), "HexArray_16");
+    let expected = serde_json::json!({
+        "type": "string",
+        "maxLength": 32,
+        "minLength": 32,
+        "pattern": "^[0-9a-fA-F]{32}$"
+    });
+    assert_eq!(schema_json::<HexArray<16>>(), expected);
+}
+
+#[test]
+fn hex_vec_schema() {
+    assert_eq!(HexVec::name(), "HexVec");
+    let expected = serde_json::json!({
+        "type": "string",
+        "pattern": "^([0-9a-fA-F]{2})*$"
+    });
+    assert_eq!(schema_json::<HexVec>(), expected);
+}
+
+#[test]
+fn base64_array_schema() {
+    assert_eq!(Base64Array::<16>::name(), "Base64Array_16");
+    let expected = serde_json::json!({
+        "type": "string",
+        "format": "byte",
+        "contentEncoding": "base64",
+        "maxLength": 24,
+        "minLength": 24,
+        "pattern": "^[A-Za-z0-9+/]{22}==$"
+    });
+    assert_eq!(schema_json::<Base64Array<16>>(), expected);
+
+    let actual = schema_json::<Base64Array<32>>();
+    assert_eq!(actual["maxLength"], 44);
+    assert_eq!(actual["pattern"], "^[A-Za-z0-9+/]{43}=$");
+}
+
+#[test]
+fn base64_vec_schemas() {
+    let expected = serde_json::json!({
+        "type": "string",
+        "format": "byte",
+        "contentEncoding": "base64"
+    });
+    assert_eq!(schema_json::<Base64Vec>(), expected);
+    assert_eq!(schema_json::<Base64Cow>(), expected);
+
+    let expected = serde_json::json!({
+        "type": "string",
+        "contentEncoding": "base64",
+        "pattern": "^([A-Za-z0-9+/]{4})*([A-Za-z0-9+/]{2,3})?$"
+    });
+    assert_eq!(schema_json::<Base64NoPadVec>(), expected);
+
+    let expected = serde_json::json!({
+        "type": "string",
+        "contentEncoding": "base64url",
+        "pattern": "^([A-Za-z0-9_-]{4})*([A-Za-z0-9_-]{2}==|[A-Za-z0-9_-]{3}=)?$"
+    });
+    assert_eq!(schema_json::<Base64UrlVec>(), expected);
+
+    let expected = serde_json::json!({
+        "type": "string",
+        "contentEncoding": "base64url",
+        "pattern": "^([A-Za-z0-9_-]{4})*([A-Za-z0-9_-]{2,3})?$"
+    });
+    assert_eq!(schema_json::<Base64UrlNoPadVec>(), expected);
+
+    let expected = serde_json::json!({
+        "type": "string",
+        "contentEncoding": "base64",
+        "pattern": "^[A-Za-z0-9+/_-]*={0,2}$"
+    });
+    assert_eq!(schema_json::<Base64LenientVec>(), expected);
+}
+
+#[test]
+fn bounded_base64_vec_schema() {
+    assert_eq!(BoundedBase64Vec::<16>::name(), "BoundedBase64Vec_16");
+    let expected = serde_json::json!({
+        "type": "string",
+        "format": "byte",
+        "contentEncoding": "base64",
+        "maxLength": 24
+    });
+    assert_eq!(schema_json::<BoundedBase64Vec<16>>(), expected);
+}
+
+#[expect(dead_code)]
+#[derive(ToSchema)]
+struct WithFields {
+    #[schema(value_type = HexArray<16>, inline)]
+    key: [u8; 16],
+    #[schema(inline)]
+    data: Base64Vec,
+}
+
+#[test]
+fn separated_hex_array_names() {
+    // The names are component keys, so each configuration needs its own.
+    assert_eq!(
+        SeparatedHexArray::<6>::name(),
+        "SeparatedHexArray_6_-3A_1_lower"
+    );
+    assert_eq!(
+        SeparatedHexArray::<6, '-', 1, true>::name(),
+        "SeparatedHexArray_6_-2D_1_upper"
+    );
+    assert_eq!(
+        SeparatedHexArray::<6, '.', 2>::name(),
+        "SeparatedHexArray_6_-2E_2_lower"
+    );
+}
+
+#[test]
+fn with_fields() {
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(WithFields)))]
+    struct Api;
+
+    let api = serde_json::to_value(Api::openapi()).expect("serialized");
+    let expected = serde_json::json!({
+        "type": "object",
+        "required": ["key", "data"],
+        "properties": {
+            "key": {
+                "type": "string",
+                "maxLength": 32,
+                "minLength": 32,
+                "pattern": "^[0-9a-fA-F]{32}$"
+            },
+            "data": {
+                "type": "string",
+                "format": "byte",
+                "contentEncoding": "base64"
+            }
+        }
+    });
+    assert_eq!(api["components"]["schemas"]["WithFields"], expected);
+}